@@ -0,0 +1,56 @@
+//! Times `VDI::hline` with solid patterns, which are filled wholesale,
+//! against a nearly solid pattern of the same length, which is drawn pixel by pixel.
+//!
+//! Run with `cargo run --release --example hline_timing`.
+
+extern crate sdl2;
+extern crate gemini;
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+use std::time::{Duration, Instant};
+
+
+const WIDTH : u16 = 640;
+const HEIGHT : u16 = 480;
+const PASSES : u32 = 200;
+
+
+/// Fills the whole surface with horizontal lines of `pattern`, `PASSES` times over,
+/// and answers how long it took.
+fn time_lines(vdi: &mut VDI, pattern: u16) -> Duration {
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        for y in 0..HEIGHT {
+            vdi.hline((0, y), WIDTH, pattern);
+        }
+    }
+    start.elapsed()
+}
+
+fn nanos_per_pixel(elapsed: Duration) -> f64 {
+    let nanos = elapsed.as_secs() as f64 * 1e9 + elapsed.subsec_nanos() as f64;
+    nanos / (PASSES as f64 * WIDTH as f64 * HEIGHT as f64)
+}
+
+fn main() {
+    let sdl = sdl2::init().unwrap();
+    let mut surface = vdi::SDL2Vdi::new(&sdl, WIDTH, HEIGHT, "hline timing").unwrap();
+    let vdi : &mut VDI = &mut surface;
+
+    // Warm up, so the first measurement doesn't pay for faulting in the frame buffer.
+    time_lines(vdi, 0xFFFF);
+
+    let white = time_lines(vdi, 0xFFFF);
+    let black = time_lines(vdi, 0x0000);
+    let patterned = time_lines(vdi, 0xFFFE);
+
+    println!("solid white:   {:.3} ns/pixel", nanos_per_pixel(white));
+    println!("solid black:   {:.3} ns/pixel", nanos_per_pixel(black));
+    println!("0xFFFE:        {:.3} ns/pixel", nanos_per_pixel(patterned));
+    println!(
+        "speed-up:      {:.1}x",
+        nanos_per_pixel(patterned) / nanos_per_pixel(white)
+    );
+}
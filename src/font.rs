@@ -8,6 +8,304 @@ pub struct Font<'a> {
     pub width:          u16,
     pub ascender:       u16,
     pub height:         u16,
+
+    /// When set, every glyph advances by this many pixels regardless of its width,
+    /// rendering a proportional font monospaced.
+    /// Narrower glyphs are centered within their cell.
+    pub fixed_pitch:    Option<u16>,
+
+    /// The glyph drawn in place of any byte the font has no glyph for.
+    pub notdef:         u8,
+}
+
+
+/// A snapshot of a font's vertical and horizontal measurements, for layout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontMetrics {
+    /// Pixels from the top of a glyph cell down to the baseline.
+    pub ascender:       u16,
+
+    /// Pixels from the baseline down to the bottom of a glyph cell.
+    pub descender:      u16,
+
+    /// Total height of a glyph cell; always `ascender + descender`.
+    pub height:         u16,
+
+    /// The largest advance of any glyph in the font;
+    /// for a fixed-pitch font, the pitch.
+    pub max_advance:    u16,
+
+    /// How far apart successive baselines should be.
+    /// Fonts carry no leading, so this is the same as `height`.
+    pub line_height:    u16,
+}
+
+
+impl<'a> Font<'a> {
+    /// Answers the font's metrics.
+    pub fn metrics(&self) -> FontMetrics {
+        let widest = self.left_edges.windows(2)
+            .map(|edges| edges[1] - edges[0])
+            .max()
+            .unwrap_or(0);
+
+        FontMetrics {
+            ascender:       self.ascender,
+            descender:      self.height - self.ascender,
+            height:         self.height,
+            max_advance:    self.fixed_pitch.unwrap_or(widest),
+            line_height:    self.height,
+        }
+    }
+
+    /// Answers true if the font has a glyph for `chr`;
+    /// that is, if `left_edges` holds both its left and right edges.
+    pub fn has_glyph(&self, chr: u8) -> bool {
+        (chr as usize + 1) < self.left_edges.len()
+    }
+
+    /// Answers which glyph renders `chr`: `chr` itself if the font has it,
+    /// otherwise the `notdef` glyph.
+    /// Answers `None` if even the `notdef` glyph is missing.
+    pub fn glyph_index(&self, chr: u8) -> Option<u8> {
+        if self.has_glyph(chr) {
+            Some(chr)
+        }
+        else if self.has_glyph(self.notdef) {
+            Some(self.notdef)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Answers how far the pen advances after drawing `chr`.
+    pub fn advance(&self, chr: u8) -> u16 {
+        match self.fixed_pitch {
+            Some(pitch) => pitch,
+            None => self.glyph_width(chr),
+        }
+    }
+
+    /// Answers the width of `chr`'s bitmap, which can differ from its advance.
+    pub fn glyph_width(&self, chr: u8) -> u16 {
+        match self.glyph_index(chr) {
+            Some(g) => self.left_edges[g as usize + 1] - self.left_edges[g as usize],
+            None => 0,
+        }
+    }
+
+    /// Answers where `chr`'s bitmap begins, horizontally, within `bits`.
+    fn glyph_edge(&self, chr: u8) -> u16 {
+        match self.glyph_index(chr) {
+            Some(g) => self.left_edges[g as usize],
+            None => 0,
+        }
+    }
+
+    /// Answers how far to the right of the pen position `chr`'s bitmap begins.
+    /// This is zero except for glyphs centered within a fixed-pitch cell.
+    fn glyph_inset(&self, chr: u8) -> u16 {
+        match self.fixed_pitch {
+            Some(pitch) => pitch.saturating_sub(self.glyph_width(chr)) / 2,
+            None => 0,
+        }
+    }
+
+    /// Draws a single glyph with its top-left corner at `at`,
+    /// mixing it with the VDI surface according to `strike_fn`
+    /// (see `VDI::copy_line` for its encoding).
+    /// Unlike `TextContext::simple_put_char`, no margins apply;
+    /// the glyph clips only where the VDI surface itself clips.
+    ///
+    /// Answers the glyph's advance, so callers can place the next glyph.
+    pub fn draw_glyph(&self, vdi: &mut vdi::VDI, chr: u8, at: (u16, u16), strike_fn: u8) -> u16 {
+        let chr_left = self.glyph_edge(chr);
+        let chr_width = self.glyph_width(chr);
+
+        vdi.copy_rect_big_endian(
+            (chr_left, 0), self.width as usize, self.bits,
+            (at.0 + self.glyph_inset(chr), at.1),
+            (chr_width, self.height),
+            strike_fn,
+        );
+
+        self.advance(chr)
+    }
+}
+
+
+/// A font which owns its glyph bitmap and edge table,
+/// such as one converted from another format at run-time.
+/// With the `serde` feature enabled, it can be serialized,
+/// so a converted font can be cached and reloaded later.
+///
+/// Use `borrow` to get a `Font` to render with.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedFont {
+    pub bits:           Vec<u16>,
+    pub left_edges:     Vec<u16>,
+    pub width:          u16,
+    pub ascender:       u16,
+    pub height:         u16,
+    pub fixed_pitch:    Option<u16>,
+    pub notdef:         u8,
+}
+
+
+impl OwnedFont {
+    /// Makes an owned copy of a borrowed font.
+    pub fn from_font(font: &Font) -> OwnedFont {
+        OwnedFont {
+            bits:       font.bits.to_vec(),
+            left_edges: font.left_edges.to_vec(),
+            width:      font.width,
+            ascender:   font.ascender,
+            height:     font.height,
+            fixed_pitch: font.fixed_pitch,
+            notdef:     font.notdef,
+        }
+    }
+
+    /// Answers a `Font` suitable for rendering, borrowing this font's tables.
+    pub fn borrow<'a>(&'a self) -> Font<'a> {
+        Font {
+            bits:       &self.bits,
+            left_edges: &self.left_edges,
+            width:      self.width,
+            ascender:   self.ascender,
+            height:     self.height,
+            fixed_pitch: self.fixed_pitch,
+            notdef:     self.notdef,
+        }
+    }
+}
+
+
+/// Holds glyphs cut out of a font's glyph strip into bitmaps of their own,
+/// each in the layout `VDI::copy_rect` takes, so text drawn over and over,
+/// such as the labels of a user interface, needn't dig into the strip each time.
+/// Glyphs are cut out the first time they're drawn.
+///
+/// A cache keeps the glyphs of each font it draws apart,
+/// so text mixing fonts, such as a font and its fallbacks, is cached as well as text in one.
+/// Fonts whose tables or layout differ count as different fonts.
+/// The cache borrows the tables of the fonts its glyphs came from,
+/// so they can't be freed, and another font's allocated in their place, while it holds them.
+pub struct GlyphCache<'a> {
+    /// Each font drawn so far, with its glyphs' bitmaps, if cut out yet, indexed by glyph.
+    fonts:      Vec<(Font<'a>, Vec<Option<Vec<u16>>>)>,
+}
+
+
+impl<'a> GlyphCache<'a> {
+    /// Answers an empty cache.
+    pub fn new() -> GlyphCache<'a> {
+        GlyphCache {
+            fonts:  Vec::new(),
+        }
+    }
+
+    /// Answers how many glyphs are cached, across all fonts.
+    pub fn len(&self) -> usize {
+        self.fonts.iter()
+            .map(|&(_, ref glyphs)| glyphs.iter().filter(|g| g.is_some()).count())
+            .sum()
+    }
+
+    /// Forgets every cached glyph, of every font.
+    pub fn clear(&mut self) {
+        self.fonts.clear();
+    }
+
+    /// Answers the bitmap of `font`'s glyph `g`, cutting it out first if need be.
+    /// The bitmap is exactly as wide as the glyph, and `font.height` rows tall.
+    fn glyph(&mut self, font: &Font<'a>, g: u8) -> &[u16] {
+        let index = match self.fonts.iter().position(|&(ref cached, _)| same_layout(cached, font)) {
+            Some(index) => index,
+            None => {
+                let cached = Font {
+                    bits:           font.bits,
+                    left_edges:     font.left_edges,
+                    width:          font.width,
+                    ascender:       font.ascender,
+                    height:         font.height,
+                    fixed_pitch:    font.fixed_pitch,
+                    notdef:         font.notdef,
+                };
+                self.fonts.push((cached, vec![None; 256]));
+                self.fonts.len() - 1
+            }
+        };
+
+        let slot = &mut self.fonts[index].1[g as usize];
+        if slot.is_none() {
+            *slot = Some(cut_glyph(font, g));
+        }
+
+        match *slot {
+            Some(ref bits) => bits,
+            None => &[],
+        }
+    }
+}
+
+
+/// Answers true if glyphs cut out of `a` would be the same as those cut out of `b`:
+/// both share the same tables, and lay their glyph strips out alike.
+fn same_layout(a: &Font, b: &Font) -> bool {
+    (a.bits.as_ptr() == b.bits.as_ptr()) && (a.bits.len() == b.bits.len())
+        && (a.left_edges.as_ptr() == b.left_edges.as_ptr()) && (a.left_edges.len() == b.left_edges.len())
+        && (a.width == b.width) && (a.height == b.height) && (a.ascender == b.ascender)
+}
+
+
+/// Copies glyph `g` out of `font`'s big-endian glyph strip
+/// into a little-endian bitmap just wide enough to hold it.
+fn cut_glyph(font: &Font, g: u8) -> Vec<u16> {
+    let left = font.left_edges[g as usize] as usize;
+    let width = font.left_edges[g as usize + 1] as usize - left;
+    let strip_words = (font.width as usize + 15) / 16;
+    let words = (width + 15) / 16;
+    let mut bits = vec![0u16; words * font.height as usize];
+
+    for y in 0..font.height as usize {
+        for x in 0..width {
+            let sx = left + x;
+            let word = font.bits.get(y * strip_words + sx / 16).cloned().unwrap_or(0);
+            if (word >> (15 - (sx & 15))) & 1 != 0 {
+                bits[y * words + x / 16] |= 1 << (x & 15);
+            }
+        }
+    }
+
+    bits
+}
+
+
+/// One line of text, as `TextContext::wrap_lines` breaks it.
+struct WrappedLine {
+    /// The byte offset of the line's first byte.
+    start:  usize,
+
+    /// The byte offset just past the line's last byte other than a space or newline,
+    /// or `start` if it has none.
+    end:    usize,
+
+    /// How far drawing the line from `start` to `end` moves the pen, in pixels.
+    width:  u32,
+}
+
+
+/// The text styles a `TextContext` can draw in, bundled together
+/// so a run of text can carry its own; see `TextContext::put_runs`.
+/// The default is plain text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextStyle {
+    pub strikethrough:  bool,
+    pub ghosted:        bool,
+    pub outline:        bool,
 }
 
 
@@ -15,11 +313,39 @@ pub struct TextContext<'a> {
     pub vdi:            &'a mut vdi::VDI,
     pub font:           &'a Font<'a>,
 
+    /// Fonts to try, in order, for bytes `font` has no glyph for;
+    /// each such byte is drawn and measured in the first of these which has it,
+    /// rather than as `font`'s `notdef` glyph.
+    /// Such glyphs share the current baseline, rising above it by their own font's ascender;
+    /// see `align_baseline_to` to make room for them.
+    pub fallbacks:      Vec<&'a Font<'a>>,
+
     // where next character goes.
     pub left:           u16,
     pub baseline:       u16,
     pub strike_fn:      u8,
 
+    /// When set, each glyph's advance is multiplied by this 16.16 fixed-point factor,
+    /// such as when a font is drawn at a scale for which its advances aren't whole pixels.
+    /// The exact pen position accumulates from glyph to glyph,
+    /// and `left` is that position rounded to the nearest pixel,
+    /// so rounding error never builds up along a line.
+    /// When `None`, advances are whole pixels, as the font gives them.
+    pub advance_scale:  Option<u32>,
+
+    /// How far the exact pen position lies from `left`, in 1/65536ths of a pixel.
+    /// Only `advance_scale` uses this; reset it to 0 whenever `left` is moved by hand.
+    pub left_fraction:  i16,
+
+    /// When set, glyphs are drawn from bitmaps cut out of the font once and kept here,
+    /// rather than from the font's glyph strip.
+    pub glyph_cache:    Option<GlyphCache<'a>>,
+
+    // text styles.
+    pub strikethrough:  bool,
+    pub ghosted:        bool,
+    pub outline:        bool,
+
     // display boundaries.
     pub left_margin:    u16,
     pub right_margin:   u16,
@@ -29,52 +355,552 @@ pub struct TextContext<'a> {
 
 
 impl<'a> TextContext<'a> {
+    /// Answers a context drawing in `font` on `vdi`, with the pen at the left edge of the surface
+    /// and the baseline placed so the first line of text sits at its top.
+    /// Glyphs are copied onto the surface as they are; no fallback font, scaling, caching,
+    /// or text style is in effect, and the margins are the edges of the surface.
+    /// Set any other field directly once the context exists.
+    pub fn new(vdi: &'a mut vdi::VDI, font: &'a Font<'a>) -> TextContext<'a> {
+        let (width, height) = vdi.dimensions();
+
+        TextContext {
+            vdi:            vdi,
+            font:           font,
+            fallbacks:      Vec::new(),
+            left:           0,
+            baseline:       font.ascender,
+            strike_fn:      vdi::raster_op_bits(vdi::RasterOp::Copy),
+            advance_scale:  None,
+            left_fraction:  0,
+            glyph_cache:    None,
+            strikethrough:  false,
+            ghosted:        false,
+            outline:        false,
+            left_margin:    0,
+            right_margin:   width,
+            top_margin:     0,
+            bottom_margin:  height,
+        }
+    }
+
+    /// Answers the byte offsets within `s` of every byte neither the current font
+    /// nor any of the fallback fonts has a glyph for, in ascending order.
+    /// Neither the context nor the VDI surface is touched.
+    pub fn missing_glyphs(&self, s: &str) -> Vec<usize> {
+        s.bytes()
+            .enumerate()
+            .filter(|&(_, chr)| !self.font_for(chr).has_glyph(chr))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Answers the byte offsets within `s` at which `flow_text` would wrap it onto a new line,
+    /// given the current font, pen position, and margins.
+    /// Each offset is where the first byte of the new line sits.
+    ///
+    /// The first line begins at `left`; subsequent lines begin at `left_margin`.
+    /// Lines break at spaces, before any word which would cross `right_margin`;
+    /// spaces themselves never force a break.
+    /// A word too wide to fit on a line by itself breaks wherever it must.
+    /// If even the first word won't fit after an indented `left`,
+    /// the first offset is 0, leaving the first line empty.
+    /// A newline always breaks the line, immediately after itself.
+    /// Advances are scaled by `advance_scale` just as `simple_put_char` scales them,
+    /// each wrapped line starting with a `left_fraction` of 0.
+    ///
+    /// Neither the context nor the VDI surface is touched.
+    pub fn wrap_positions(&self, s: &str) -> Vec<usize> {
+        self.wrap_lines(s).iter().skip(1).map(|line| line.start).collect()
+    }
+
+    /// Draws `s` one `simple_put_char` at a time, wrapping it as `wrap_positions` describes.
+    /// Each line after the first starts at `left_margin`, one line height below the last.
+    /// Newlines, and spaces ending a line, aren't drawn.
+    /// Afterwards the pen sits just past the last character drawn,
+    /// or at the start of a fresh line if `s` ends with a newline.
+    pub fn flow_text(&mut self, s: &str) {
+        let bytes = s.as_bytes();
+        let line_height = self.font.metrics().line_height;
+
+        for (n, line) in self.wrap_lines(s).iter().enumerate() {
+            if n > 0 {
+                self.left = self.left_margin;
+                self.left_fraction = 0;
+                self.baseline = self.baseline.saturating_add(line_height);
+            }
+
+            for &chr in bytes[line.start..line.end].iter() {
+                self.simple_put_char(chr);
+            }
+        }
+    }
+
+    /// Answers the width and height, in pixels, of the block `s` would occupy
+    /// once wrapped as `flow_text` wraps it, such as to size a dialog around it.
+    /// The width is that of the widest line, not counting spaces or a newline ending it,
+    /// nor the indent of a first line starting right of `left_margin`,
+    /// and takes `advance_scale` into account.
+    /// The height is the number of lines times the font's line height;
+    /// a newline ending `s` doesn't start another line.
+    ///
+    /// Neither the context nor the VDI surface is touched.
+    pub fn measure_block(&self, s: &str) -> (u16, u16) {
+        if s.is_empty() {
+            return (0, 0);
+        }
+
+        let lines = self.wrap_lines(s);
+        let count = lines.iter().enumerate().filter(|&(n, line)| (n == 0) || (line.start < s.len())).count();
+        let widest = lines.iter().map(|line| line.width).max().unwrap_or(0);
+
+        let height = count as u32 * self.font.metrics().line_height as u32;
+        (min(widest, 0xFFFF) as u16, min(height, 0xFFFF) as u16)
+    }
+
+    /// Answers the byte offset within `s` of the character whose cell contains
+    /// the pixel `x` pixels right of `left`, were `s` drawn from the current pen position
+    /// one `simple_put_char` at a time; such as to place a text cursor where the mouse clicked.
+    /// Each cell spans its character's advance, `advance_scale` included.
+    /// Answers the length of `s` if `x` lies past its end.
+    ///
+    /// Neither the context nor the VDI surface is touched.
+    pub fn char_index_at(&self, s: &str, x: u16) -> usize {
+        let target = self.left as u32 + x as u32;
+        let mut left = self.left as u32;
+        let mut fraction = self.left_fraction;
+
+        for (i, chr) in s.bytes().enumerate() {
+            let advance = self.font_for(chr).advance(chr);
+            let (next_left, next_fraction) = self.pen_after(left, fraction, advance);
+            if target < next_left {
+                return i;
+            }
+            left = next_left;
+            fraction = next_fraction;
+        }
+
+        s.len()
+    }
+
+    /// Lowers `baseline`, if needed, so that glyphs of `other` fit on the current line.
+    /// The line's top is taken to be where the current font's glyphs begin;
+    /// if `other` has the taller ascender, the baseline moves down by the difference,
+    /// and otherwise it stays put.
+    /// Call this before drawing a line which mixes fonts;
+    /// each font then shares one baseline, whichever is current.
+    /// `left` is not changed.
+    ///
+    /// As each call measures from wherever the baseline has got to,
+    /// calling this twice for the same font moves the baseline twice;
+    /// see `fit_baseline_to` for a form which can be repeated.
+    pub fn align_baseline_to(&mut self, other: &Font) {
+        if other.ascender > self.font.ascender {
+            self.baseline += other.ascender - self.font.ascender;
+        }
+    }
+
+    /// Lowers `baseline`, if needed, so that glyphs of `other` fit on the line whose top is `line_top`;
+    /// that is, so their ascenders rise no higher than `line_top`.
+    /// Otherwise the baseline stays put.
+    /// As the line's top stays fixed, the order of the calls doesn't matter,
+    /// and repeating one changes nothing.
+    /// `left` is not changed.
+    pub fn fit_baseline_to(&mut self, line_top: u16, other: &Font) {
+        self.baseline = max(self.baseline, line_top.saturating_add(other.ascender));
+    }
+
+    /// Answers the width, height, and ascender of `chr` as drawn in the current font,
+    /// or in the fallback font it comes from.
+    /// Bytes no font has measure as the current font's `notdef` glyph,
+    /// or as zero width if it lacks that too; no byte panics.
     pub fn get_real_size(&self, chr: u8) -> (u16, u16, u16) {
-        let font = self.font;
-        let chr_left = font.left_edges[chr as usize];
-        let chr_right = font.left_edges[(chr+1) as usize];
-        let width = chr_right - chr_left;
+        let font = self.font_for(chr);
+        let width = font.glyph_width(chr);
         let height = font.height;
         let ascender = font.ascender;
 
         (width, height, ascender)
     }
 
+    /// Answers the context's current text style.
+    pub fn style(&self) -> TextStyle {
+        TextStyle {
+            strikethrough:  self.strikethrough,
+            ghosted:        self.ghosted,
+            outline:        self.outline,
+        }
+    }
+
+    /// Sets the context's text style.
+    pub fn set_style(&mut self, style: TextStyle) {
+        self.strikethrough = style.strikethrough;
+        self.ghosted = style.ghosted;
+        self.outline = style.outline;
+    }
+
+    /// Draws each run of text in its own style, one `simple_put_char` at a time,
+    /// the pen carrying on from one run into the next.
+    /// Afterwards, the context's style is as it was before.
+    pub fn put_runs(&mut self, runs: &[(&str, TextStyle)]) {
+        let style = self.style();
+
+        for &(s, run_style) in runs.iter() {
+            self.set_style(run_style);
+            for chr in s.bytes() {
+                self.simple_put_char(chr);
+            }
+        }
+
+        self.set_style(style);
+    }
+
     pub fn simple_put_char(&mut self, chr: u8) {
-        let vdi = &mut self.vdi;
+        let font = self.font;
+        self.font = self.font_for(chr);
+        self.put_glyph(chr);
+        self.font = font;
+    }
+
+    /// Breaks `s` into lines, as `wrap_positions` describes.
+    /// There is always at least one line, even if `s` is empty.
+    fn wrap_lines(&self, s: &str) -> Vec<WrappedLine> {
+        let bytes = s.as_bytes();
+        let left_margin = self.left_margin as u32;
+        let right_margin = self.right_margin as u32;
+        let mut lines = Vec::new();
+        let mut line = WrappedLine { start: 0, end: 0, width: 0 };
+        let mut origin = self.left as u32;
+        let mut x = origin;
+        let mut fraction = self.left_fraction;
+        // An indented first line counts as occupied, so a word which won't
+        // fit after the indent moves down rather than splitting.
+        let mut line_empty = x <= left_margin;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\n' => {
+                    lines.push(line);
+                    line = WrappedLine { start: i + 1, end: i + 1, width: 0 };
+                    origin = left_margin;
+                    x = left_margin;
+                    fraction = 0;
+                    line_empty = true;
+                    i += 1;
+                }
+
+                b' ' => {
+                    let advance = self.font_for(b' ').advance(b' ');
+                    let (next_x, next_fraction) = self.pen_after(x, fraction, advance);
+                    x = next_x;
+                    fraction = next_fraction;
+                    i += 1;
+                }
+
+                _ => {
+                    let start = i;
+                    let mut end = i;
+                    let (mut word_end, mut word_fraction) = (x, fraction);
+                    while (end < bytes.len()) && (bytes[end] != b' ') && (bytes[end] != b'\n') {
+                        let advance = self.font_for(bytes[end]).advance(bytes[end]);
+                        let (next_x, next_fraction) = self.pen_after(word_end, word_fraction, advance);
+                        word_end = next_x;
+                        word_fraction = next_fraction;
+                        end += 1;
+                    }
+
+                    if !line_empty && (word_end > right_margin) {
+                        lines.push(line);
+                        line = WrappedLine { start: start, end: start, width: 0 };
+                        origin = left_margin;
+                        x = left_margin;
+                        fraction = 0;
+                        line_empty = true;
+                    }
+
+                    for j in start..end {
+                        let advance = self.font_for(bytes[j]).advance(bytes[j]);
+                        let (mut next_x, mut next_fraction) = self.pen_after(x, fraction, advance);
+                        if !line_empty && (next_x > right_margin) {
+                            lines.push(line);
+                            line = WrappedLine { start: j, end: j, width: 0 };
+                            origin = left_margin;
+                            let (wrapped_x, wrapped_fraction) = self.pen_after(left_margin, 0, advance);
+                            next_x = wrapped_x;
+                            next_fraction = wrapped_fraction;
+                        }
+                        x = next_x;
+                        fraction = next_fraction;
+                        line_empty = false;
+                        line.end = j + 1;
+                        line.width = x - origin;
+                    }
+
+                    i = end;
+                }
+            }
+        }
+
+        lines.push(line);
+        lines
+    }
+
+    /// Answers the font `chr` is drawn in: the current font if it has a glyph for it,
+    /// else the first fallback font which has one, else the current font's `notdef` glyph.
+    fn font_for(&self, chr: u8) -> &'a Font<'a> {
+        if self.font.has_glyph(chr) {
+            return self.font;
+        }
+
+        match self.fallbacks.iter().find(|fallback| fallback.has_glyph(chr)) {
+            Some(fallback) => fallback,
+            None => self.font,
+        }
+    }
+
+    /// Draws `chr` in the current font and advances past it, as `simple_put_char` does,
+    /// without considering the fallback fonts.
+    fn put_glyph(&mut self, chr: u8) {
+        let (from, to, dimensions) = match self.glyph_clip(chr, (0, 0)) {
+            Some(clip) => clip,
+            None => return,  // outside the visible window; nothing to show.
+        };
+
+        if self.outline {
+            self.halo(chr);
+        }
+
+        match self.glyph_cache {
+            _ if self.ghosted => {
+                let bits = self.ghosted_bits(from, to, dimensions);
+                self.vdi.copy_rect((0, 0), dimensions.0 as usize, &bits, to, dimensions, self.strike_fn);
+            }
+
+            Some(ref mut cache) => {
+                let font = self.font;
+                let edge = font.glyph_edge(chr);
+                let width = font.glyph_width(chr);
+                if let Some(g) = font.glyph_index(chr) {
+                    self.vdi.copy_rect(
+                        (from.0 - edge, from.1), width as usize, cache.glyph(font, g),
+                        to, dimensions,
+                        self.strike_fn,
+                    );
+                }
+            }
+
+            None =>
+                self.vdi.copy_rect_big_endian(
+                    from, self.font.width as usize, self.font.bits,
+                    to, dimensions,
+                    self.strike_fn,
+                ),
+        }
+
+        if self.strikethrough {
+            self.strike_through(chr);
+        }
+
+        let advance = self.font.advance(chr);
+        self.advance_pen(advance);
+    }
+
+    /// Moves the pen `advance` pixels to the right, scaled by `advance_scale` if set.
+    fn advance_pen(&mut self, advance: u16) {
+        let (left, fraction) = self.pen_after(self.left as u32, self.left_fraction, advance);
+        self.left = min(left, 0xFFFF) as u16;
+        self.left_fraction = fraction;
+    }
+
+    /// Answers where a pen at `left`, plus `fraction` 1/65536ths of a pixel,
+    /// ends up after moving `advance` pixels to the right, scaled by `advance_scale` if set.
+    /// Every pen position text is drawn or measured at comes from here,
+    /// so that measuring a string agrees with drawing it.
+    fn pen_after(&self, left: u32, fraction: i16, advance: u16) -> (u32, i16) {
+        match self.advance_scale {
+            None => (left + advance as u32, fraction),
+
+            Some(scale) => {
+                let exact = ((left as i64) << 16)
+                    + fraction as i64
+                    + advance as i64 * scale as i64;
+                let rounded = (exact + 0x8000) >> 16;
+                (rounded as u32, (exact - (rounded << 16)) as i16)
+            }
+        }
+    }
+
+    /// Answers where to find `chr`'s bitmap within the font, where to draw it,
+    /// and how much of it to draw, once clipped to the margins.
+    /// `offset` displaces the glyph from its usual place at the current position.
+    /// Answers `None` if none of the glyph would be visible.
+    fn glyph_clip(&self, chr: u8, offset: (i32, i32)) -> Option<((u16, u16), (u16, u16), (u16, u16))> {
         let font = self.font;
 
-        let chr_left = font.left_edges[chr as usize];
-        let vdi_top = self.baseline - font.ascender;
-        let vdi_top_clipped = max(vdi_top, self.top_margin);
-        let chr_top_clipped = vdi_top_clipped - vdi_top;
-        let vdi_bottom = vdi_top + font.height;
-        let vdi_bottom_clipped = min(self.bottom_margin, vdi_bottom);
+        // Ascenders rise above the baseline; descenders hang below it.
+        let baseline = self.baseline as i32 + offset.1;
+        let descent = font.height as i32 - font.ascender as i32;
+        let vdi_top = baseline - font.ascender as i32;
+        let vdi_top_clipped = max(vdi_top, self.top_margin as i32);
+        let vdi_bottom = baseline + descent;
+        let vdi_bottom_clipped = min(self.bottom_margin as i32, vdi_bottom);
         if vdi_top_clipped >= vdi_bottom_clipped {
-            return;  // outside the visible window; nothing to show.
+            return None;
         }
-        let chr_height_clipped = vdi_bottom_clipped - vdi_top_clipped;
 
-        let chr_right = font.left_edges[(chr+1) as usize];
-        let chr_width = chr_right - chr_left;
-        let vdi_left_clipped = max(self.left_margin, self.left);
-        let vdi_right_clipped = min(self.right_margin, self.left + chr_width);
+        let glyph_left = (self.left + font.glyph_inset(chr)) as i32 + offset.0;
+        let vdi_left_clipped = max(self.left_margin as i32, glyph_left);
+        let vdi_right_clipped = min(self.right_margin as i32, glyph_left + font.glyph_width(chr) as i32);
         if vdi_left_clipped >= vdi_right_clipped {
-            return;  // outside the visible window; nothing to show.
+            return None;
         }
-        let delta_x = vdi_left_clipped - self.left;
-        let chr_left_clipped = chr_left + delta_x;
-        let chr_width_clipped = min(chr_width, vdi_right_clipped - vdi_left_clipped);
 
-        vdi.copy_rect_big_endian(
-            (chr_left_clipped, chr_top_clipped), font.width as usize, font.bits,
-            (vdi_left_clipped, vdi_top_clipped),
-            (chr_width_clipped, chr_height_clipped),
-            self.strike_fn,
-        );
+        Some((
+            ((font.glyph_edge(chr) as i32 + vdi_left_clipped - glyph_left) as u16,
+             (vdi_top_clipped - vdi_top) as u16),
+            (vdi_left_clipped as u16, vdi_top_clipped as u16),
+            ((vdi_right_clipped - vdi_left_clipped) as u16,
+             (vdi_bottom_clipped - vdi_top_clipped) as u16),
+        ))
+    }
+
+    /// Surrounds `chr` with a one-pixel ring in the pen opposite its ink,
+    /// by drawing its set pixels shifted one pixel in each of the eight directions.
+    /// Clear pixels leave the surface alone.
+    /// The ink is taken to be what `strike_fn` makes of a set pixel on black.
+    fn halo(&mut self, chr: u8) {
+        let halo_fn = if self.strike_fn & 0b0010 != 0 {
+            vdi::raster_op_bits(vdi::RasterOp::Erase)
+        }
+        else {
+            vdi::raster_op_bits(vdi::RasterOp::Or)
+        };
 
-        self.left += chr_width;
+        for &offset in HALO_OFFSETS.iter() {
+            if let Some((from, to, dimensions)) = self.glyph_clip(chr, offset) {
+                self.vdi.copy_rect_big_endian(
+                    from, self.font.width as usize, self.font.bits,
+                    to, dimensions,
+                    halo_fn,
+                );
+            }
+        }
     }
+
+    /// Answers the part of the font's glyph strip at `from`, of size `dimensions`,
+    /// as a little-endian bitmap keeping only those set pixels `vdi::GRAY50` also sets,
+    /// for the classic disabled look.
+    /// Only the glyph's ink is thinned; drawn through `strike_fn`, its clear pixels
+    /// treat the surface just as they would for an unghosted glyph.
+    /// The dither aligns with the surface at `to`, like any other pattern.
+    fn ghosted_bits(&self, from: (u16, u16), to: (u16, u16), dimensions: (u16, u16)) -> Vec<u16> {
+        let font = self.font;
+        let (origin_x, origin_y) = self.vdi.origin();
+        let strip_words = (font.width as usize + 15) / 16;
+        let words = (dimensions.0 as usize + 15) / 16;
+        let mut bits = vec![0u16; words * dimensions.1 as usize];
+
+        for y in 0..dimensions.1 as usize {
+            let sy = from.1 as usize + y;
+            let row = vdi::GRAY50[((to.1 as i32 + y as i32 + origin_y as i32) & 15) as usize];
+            for x in 0..dimensions.0 as usize {
+                let sx = from.0 as usize + x;
+                let word = font.bits.get(sy * strip_words + sx / 16).cloned().unwrap_or(0);
+                let ink = (word >> (15 - (sx & 15))) & 1 != 0;
+                let gray = (row >> ((to.0 as i32 + x as i32 + origin_x as i32) & 15)) & 1 != 0;
+                if ink && gray {
+                    bits[y * words + x / 16] |= 1 << (x & 15);
+                }
+            }
+        }
+
+        bits
+    }
+
+    /// Draws a line through the vertical middle of `chr`'s cell at the current
+    /// position, across its whole advance, `advance_scale` included, clipped to the margins.
+    /// The line is drawn as a solid source using `strike_fn`, so it takes on
+    /// the same appearance as the glyph's own set pixels.
+    fn strike_through(&mut self, chr: u8) {
+        // A baseline near the top of the surface may put the middle of the cell above it.
+        let font = self.font_for(chr);
+        let y = self.baseline as i32 - (font.ascender / 2) as i32;
+        if (y < self.top_margin as i32) || (y >= self.bottom_margin as i32) {
+            return;
+        }
+        let y = y as u16;
+
+        let left = max(self.left_margin, self.left);
+        let (end, _) = self.pen_after(self.left as u32, self.left_fraction, font.advance(chr));
+        let right = min(self.right_margin as u32, end) as u16;
+
+        let mut x = left;
+        while x < right {
+            let width = min(16, right - x);
+            self.vdi.copy_line((0, 0), 16, &SOLID, (x, y), width as usize, self.strike_fn);
+            x += width;
+        }
+    }
+}
+
+
+/// A single row of set pixels, used to draw text decorations.
+static SOLID : [u16; 1] = [0xFFFF];
+
+static HALO_OFFSETS : [(i32, i32); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1,  0),          (1,  0),
+    (-1,  1), (0,  1), (1,  1),
+];
+
+
+/// Computes the `left_edges` table for a fixed-width font
+/// whose `glyph_count` glyphs are packed side by side, each `glyph_width` pixels wide.
+/// The result holds `glyph_count + 1` entries;
+/// the last marks the right edge of the final glyph.
+pub fn edges_from_fixed(glyph_count: u16, glyph_width: u16) -> Vec<u16> {
+    (0..glyph_count as u32 + 1).map(|i| (i * glyph_width as u32) as u16).collect()
+}
+
+
+/// Computes the `left_edges` table for a proportional font
+/// by looking for empty columns between glyphs in a single-row strip.
+///
+/// `bits` holds the strip in the same big-endian format as `Font::bits`,
+/// `strip_width` pixels wide and `height` pixels tall.
+/// A column is empty when every pixel in it matches `gap_col_value`,
+/// which is 0 for clear bits or 1 for set bits.
+///
+/// Each glyph begins at the first non-empty column following an empty one,
+/// and extends up to where the next glyph begins,
+/// so empty columns to a glyph's right become part of its advance.
+/// Empty columns before the first glyph are skipped.
+/// The last entry is always `strip_width`.
+pub fn edges_from_gaps(bits: &[u16], strip_width: u16, height: u16, gap_col_value: u8) -> Vec<u16> {
+    let span = (strip_width as usize + 15) / 16;
+    let gap_bit = if gap_col_value != 0 { 1 } else { 0 };
+    let mut edges = Vec::new();
+    let mut in_glyph = false;
+
+    for x in 0..strip_width as usize {
+        let mut empty = true;
+        for y in 0..height as usize {
+            let word = bits[y * span + x / 16];
+            let bit = (word >> (15 - (x & 15))) & 1;
+            if bit != gap_bit {
+                empty = false;
+                break;
+            }
+        }
+
+        if !empty && !in_glyph {
+            edges.push(x as u16);
+        }
+        in_glyph = !empty;
+    }
+
+    edges.push(strip_width);
+    edges
 }
 
 
@@ -88,6 +914,8 @@ pub static SYSTEM_FONT : Font<'static> = Font {
     left_edges:     &system_font::SYSTEM_LEFT_EDGES,
     width:          256*8,
     ascender:       7,
-    height:         8
+    height:         8,
+    fixed_pitch:    None,
+    notdef:         b'?',
 };
 
@@ -21,6 +21,9 @@
 
 extern crate sdl2;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
 
 pub mod vdi;
 pub mod font;
@@ -4,16 +4,18 @@
 //! It provides basic primitives for displaying simple graphics.
 //!
 //! Influenced more by GEOS than by GEM's VDI, this module allows
-//! applications to scribble on the entire display surface.  No
-//! support for clipping yet exists,
-//! except for the edges of the display surface of course.
+//! applications to scribble on the entire display surface.
+//! Drawing clips to the edges of the display surface,
+//! or to a rectangle within it by way of a `Viewport`.
 
 
 use sdl2;
 use sdl2::{pixels, render, video};
 
-use std::{mem, result};
-use std::cmp::min;
+use std::{io, mem, result, thread, time};
+use std::cmp::{max, min};
+use std::io::{Read, Write};
+use std::ops;
 
 
 /// Indication of an error somewhere inside the VDI module.
@@ -24,11 +26,80 @@ pub enum VdiError {
 }
 
 
+/// A 50% checkerboard dither, suitable for `VDI::rect` and friends.
+/// Pixel (x, y) is set wherever `x + y` is odd.
+pub static GRAY50 : [u16; 16] = [
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+];
+
+
+/// Solid fill patterns, for internal use.
+static SOLID_WHITE : [u16; 16] = [0xFFFF; 16];
+static SOLID_BLACK : [u16; 16] = [0x0000; 16];
+
+
+/// Named raster operations, for use with the `function` parameter of
+/// `VDI::copy_line`, `VDI::copy_rect`, and their big-endian variants.
+/// Convert one into its function byte with `raster_op_bits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RasterOp {
+    /// Destination becomes the source: `S`.
+    Copy,
+    /// Destination becomes the inverted source: `!S`.
+    NotSrc,
+    /// Source pixels which are set, set the destination: `D | S`.
+    Or,
+    /// Source pixels which are clear, clear the destination: `D & S`.
+    And,
+    /// Source pixels which are set, invert the destination: `D ^ S`.
+    Xor,
+    /// Source pixels which are set, clear the destination: `D & !S`.
+    Erase,
+    /// Destination is inverted, regardless of the source: `!D`.
+    NotDst,
+    /// Destination is left alone: `D`.
+    Nop,
+    /// Destination is cleared, regardless of the source.
+    ClearDst,
+    /// Destination is set, regardless of the source.
+    SetDst,
+}
+
+
+/// Answers the `function` byte which performs the given raster operation.
+/// Only the low four bits are significant to the VDI;
+/// they're repeated in the high four bits so the value reads the same either way.
+pub fn raster_op_bits(op: RasterOp) -> u8 {
+    let truth_table = match op {
+        RasterOp::Copy      => 0b1010,
+        RasterOp::NotSrc    => 0b0101,
+        RasterOp::Or        => 0b1110,
+        RasterOp::And       => 0b1000,
+        RasterOp::Xor       => 0b0110,
+        RasterOp::Erase     => 0b0100,
+        RasterOp::NotDst    => 0b0011,
+        RasterOp::Nop       => 0b1100,
+        RasterOp::ClearDst  => 0b0000,
+        RasterOp::SetDst    => 0b1111,
+    };
+
+    (truth_table << 4) | truth_table
+}
+
+
 /// VDI drivers must conform to this interface.
 ///
 /// A word about color indices.  Currently, only two indices are supported.
 /// Indices 0...127 corresponds to black, while indices 128...255 corresponds
 /// to white.  For future compatibility, use index 255 to refer to white.
+///
+/// Primitives which can be built entirely from others,
+/// such as frames, tiling, and most of the `copy_rect` variants,
+/// come with default implementations in terms of the rest.
+/// A driver need only override them where it can do better.
 pub trait VDI {
     /// Draw a single point at the provided coordinates.  Attempts to draw beyond
     /// the edge of the surface will simply be ignored.
@@ -37,12 +108,117 @@ pub trait VDI {
     /// Retrieves the current pixel value at a given position.
     fn get_point(&self, at: (u16, u16)) -> u8;
 
+    /// Answers the average value, rounded, of the pixels in a `kernel` by `kernel` box
+    /// around `at`, such as to read the surface back at a reduced size for a thumbnail.
+    /// The box begins `kernel / 2` pixels left of and above `at`.
+    /// Being monochrome, the surface answers how much of the box is white, scaled to 0..255.
+    ///
+    /// Only pixels on the surface count towards the average;
+    /// if none of the box lies on the surface, answers 0.
+    /// A `kernel` of 0 or 1 reads `at` alone, as `get_point` does.
+    fn get_point_avg(&self, at: (u16, u16), kernel: u16) -> u8 {
+        let kernel = max(kernel, 1) as i32;
+        let left = at.0 as i32 - kernel / 2;
+        let top = at.1 as i32 - kernel / 2;
+        let mut sum : u64 = 0;
+        let mut count : u64 = 0;
+
+        for y in max(top, 0)..min(top + kernel, 0x10000) {
+            for x in max(left, 0)..min(left + kernel, 0x10000) {
+                let point = (x as u16, y as u16);
+                if self.point_visible(point) {
+                    sum += self.get_point(point) as u64;
+                    count += 1;
+                }
+            }
+        }
+
+        if count == 0 {
+            0
+        }
+        else {
+            ((sum + count / 2) / count) as u8
+        }
+    }
+
+    /// Answers true if drawing at `at` would touch the surface;
+    /// that is, if the point lies on the surface once the origin is added.
+    fn point_visible(&self, at: (u16, u16)) -> bool;
+
+    /// Answers true if `at` lies within `dimensions`, taken literally;
+    /// unlike `point_visible`, the origin plays no part.
+    fn in_bounds(&self, at: (u16, u16)) -> bool {
+        let (width, height) = self.dimensions();
+        (at.0 < width) && (at.1 < height)
+    }
+
+    /// Retrieves up to 64 consecutive pixels of a row, starting at `at`,
+    /// packed into a bit vector.  Bit 0 holds the leftmost pixel, bit 1 the next,
+    /// and so forth; a bit is set wherever `get_point` would answer white.
+    /// Pixels beyond `width`, or beyond the edges of the surface, read as 0.
+    fn get_hline_bits(&self, at: (u16, u16), width: u16) -> u64;
+
+    /// Answers the logical dimensions of the surface, in pixels.
+    /// This is the coordinate space all other drawing primitives work in,
+    /// regardless of how large the surface appears on the display.
+    fn dimensions(&self) -> (u16, u16);
+
+    /// Answers a 64-bit FNV-1a hash of the pixels of the rectangle at `at`, of size `dimensions`,
+    /// row by row; such as to tell cheaply whether a region changed between frames.
+    /// Pixels falling off the surface are left out of the hash.
+    fn region_checksum(&self, at: (u16, u16), dimensions: (u16, u16)) -> u64 {
+        let mut hash : u64 = 0xCBF29CE484222325;
+
+        for y in (at.1 as u32)..(at.1 as u32 + dimensions.1 as u32) {
+            for x in (at.0 as u32)..(at.0 as u32 + dimensions.0 as u32) {
+                let point = (x as u16, y as u16);
+                if (x <= 0xFFFF) && (y <= 0xFFFF) && self.point_visible(point) {
+                    hash ^= self.get_point(point) as u64;
+                    hash = hash.wrapping_mul(0x100000001B3);
+                }
+            }
+        }
+
+        hash
+    }
+
+    /// Sets the origin of the drawing coordinate system.
+    /// Every coordinate given to a drawing primitive or to `get_point`
+    /// has the origin added to it before clipping to the surface.
+    /// This lets a widget draw in its own local coordinates,
+    /// leaving it to its container to position it on the surface.
+    ///
+    /// The origin may be negative, in which case content falling off the
+    /// top and/or left edges of the surface is clipped.
+    /// Patterns remain aligned to the surface, not to the origin.
+    fn set_origin(&mut self, x: i16, y: i16);
+
+    /// Answers the origin last set with `set_origin`.  Defaults to (0, 0).
+    fn origin(&self) -> (i16, i16);
+
+    /// Answers the pen value at and above which pens draw white, rather than black.
+    /// Surfaces without a threshold of their own split the range in half, at 128.
+    fn threshold(&self) -> u8 {
+        128
+    }
+
+    /// Answers a copy of the entire frame buffer, one byte per pixel,
+    /// in row-major order.  The origin plays no part in this.
+    /// Compare two snapshots with `assert_snapshots_eq`.
+    fn snapshot(&self) -> Vec<u8>;
+
     /// Commit sends the current contents of the VDI frame buffer
     /// to the attached display.  Typically, a program would draw into the
     /// frame buffer, and then call `commit` to make the drawing visible to
     /// the user.  Note that this procedure updates the entire frame buffer.
     fn commit(&mut self) -> result::Result<(), VdiError>;
 
+    /// As with `commit`, but answers the region of the frame buffer
+    /// actually sent to the display, as `(left, top, right, bottom)`
+    /// in surface pixels; `right` and `bottom` are exclusive.
+    /// Embedders can forward this damaged region to a parent surface.
+    fn commit_reporting(&mut self) -> result::Result<(u16, u16, u16, u16), VdiError>;
+
     /// Draw a horizontal line on the VDI surface using the provided pattern.
     /// Coordinates are clipped to the edges of the surface only.
     /// The pattern is naturally aligned with the left edge of the surface,
@@ -56,6 +232,13 @@ pub trait VDI {
     /// to end the line.  The horizontal range covered is `[at.x, to)`.
     fn hline(&mut self, at: (u16, u16), to: u16, pattern: u16);
 
+    /// As with `hline`, except the pattern starts at bit `phase` (modulo 16)
+    /// at the line's left end, rather than aligning with the surface.
+    /// Pixels clipped off the left edge still consume pattern bits,
+    /// so clipping never shifts the visible pattern.
+    /// Incrementing `phase` from frame to frame slides the pattern along the line.
+    fn hline_phased(&mut self, at: (u16, u16), to: u16, pattern: u16, phase: u8);
+
     /// Draw a vertical line on the VDI surface using the provided pattern.
     /// Coordinates are clipped to the edges of the surface only.
     /// The pattern is naturally aligned with the top edge of the surface,
@@ -75,11 +258,252 @@ pub trait VDI {
     /// The pattern is aligned to the left and top edge of the VDI surface.
     /// You can draw several overlapping and/or adjacent filled rectangles,
     /// and the pattern will be continuous.
-    fn rect(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16]);
+    fn rect(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16]) {
+        self.rect_offset(at, to, pattern, (0, 0));
+    }
+
+    /// Draw several filled rectangles with the same pattern, as if by calling `rect`
+    /// once for each `(at, to)` pair in `rects`.
+    /// Each rectangle is clipped independently.
+    fn fill_rects(&mut self, rects: &[((u16, u16), (u16, u16))], pattern: &[u16; 16]) {
+        for &(at, to) in rects {
+            self.rect(at, to, pattern);
+        }
+    }
+
+    /// As with `rect`, but the pattern is shifted by `offset` relative to the surface.
+    /// Row `(y + offset.1) & 15` of the pattern fills surface row `y`,
+    /// and pixel `x` takes bit `(x + offset.0) & 15` of that row.
+    /// Advancing the offset along with a scroll position keeps the fill's
+    /// phase continuous with the scrolled content.
+    fn rect_offset(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16], offset: (u16, u16));
+
+    /// Fill the rectangle from `at` to `to` with copies of a small bitmap, laid edge to edge,
+    /// for textures too large or irregular for a 16x16 pattern.
+    /// The bitmap, `tile_dims` pixels in size, is laid out as the source bitmap of `copy_rect`.
+    /// Unlike patterns, tiling begins with a whole tile at the rectangle's top-left corner;
+    /// partial tiles along the right and bottom edges are clipped.
+    fn tile_rect(&mut self, at: (u16, u16), to: (u16, u16), tile_bits: &[u16], tile_dims: (u16, u16)) {
+        self.scroll_tiled(at, to, tile_bits, tile_dims, (0, 0));
+    }
+
+    /// As with `tile_rect`, but with the tiling scrolled by `offset`, wrapping around,
+    /// such as for a background which scrolls seamlessly.
+    /// As with `rect_offset`, the pixel `x` pixels right of and `y` pixels below
+    /// the rectangle's top-left corner takes tile pixel
+    /// `((x + offset.0) % tile_dims.0, (y + offset.1) % tile_dims.1)`;
+    /// an offset of `(0, 0)` tiles just as `tile_rect` does.
+    fn scroll_tiled(&mut self, at: (u16, u16), to: (u16, u16), tile_bits: &[u16], tile_dims: (u16, u16), offset: (u16, u16)) {
+        let left = min(at.0, to.0) as u32;
+        let top = min(at.1, to.1) as u32;
+        let right = max(at.0, to.0) as u32;
+        let bottom = max(at.1, to.1) as u32;
+        let (tile_width, tile_height) = (tile_dims.0 as u32, tile_dims.1 as u32);
+
+        if (tile_width == 0) || (tile_height == 0) {
+            return;
+        }
+
+        // The first row and column of tiles begin part way into the tile.
+        let mut y = top;
+        let mut src_y = offset.1 as u32 % tile_height;
+        while y < bottom {
+            let rows = min(tile_height - src_y, bottom - y);
+            let mut x = left;
+            let mut src_x = offset.0 as u32 % tile_width;
+            while x < right {
+                let columns = min(tile_width - src_x, right - x);
+                self.copy_rect(
+                    (src_x as u16, src_y as u16), tile_width as usize, tile_bits,
+                    (x as u16, y as u16),
+                    (columns as u16, rows as u16),
+                    raster_op_bits(RasterOp::Copy)
+                );
+                x += columns;
+                src_x = 0;
+            }
+            y += rows;
+            src_y = 0;
+        }
+    }
+
+    /// Draw a magnified copy of part of the surface onto another part of it,
+    /// such as for a pixel editor's zoom view.
+    /// The `src_dims`-sized region at `src_at` is redrawn at `dst_at`,
+    /// with each pixel enlarged to a solid `factor`x`factor` block.
+    /// The source is read in full before anything is drawn,
+    /// so the two regions may overlap.
+    /// The magnified image is clipped at the edges of the surface.
+    fn magnify(&mut self, src_at: (u16, u16), src_dims: (u16, u16), dst_at: (u16, u16), factor: u8) {
+        let mut pixels = Vec::with_capacity(src_dims.0 as usize * src_dims.1 as usize);
+        for y in 0..src_dims.1 {
+            for x in 0..src_dims.0 {
+                pixels.push(self.get_point((src_at.0.saturating_add(x), src_at.1.saturating_add(y))));
+            }
+        }
+
+        let factor = factor as u32;
+        let mut i = 0;
+        for y in 0..src_dims.1 as u32 {
+            for x in 0..src_dims.0 as u32 {
+                let pattern = if pixels[i] >= 128 { &SOLID_WHITE } else { &SOLID_BLACK };
+                let left = min(dst_at.0 as u32 + x * factor, 0xFFFF) as u16;
+                let top = min(dst_at.1 as u32 + y * factor, 0xFFFF) as u16;
+                let right = min(left as u32 + factor, 0xFFFF) as u16;
+                let bottom = min(top as u32 + factor, 0xFFFF) as u16;
+                self.rect((left, top), (right, bottom), pattern);
+                i += 1;
+            }
+        }
+    }
 
     /// Draw an unfilled rectangular frame starting at `at` and extending to `to`.
     /// Use the supplied line pattern.
-    fn frame(&mut self, at: (u16, u16), to: (u16, u16), pattern: u16);
+    fn frame(&mut self, at: (u16, u16), to: (u16, u16), pattern: u16) {
+        self.frame_edges(at, to, pattern, pattern, pattern, pattern);
+    }
+
+    /// As with `frame`, but each edge is drawn with a line pattern of its own,
+    /// such as to set off a window's title bar.
+    /// The left and right edges run the full height of the frame,
+    /// so the corners take their patterns.
+    /// A frame with no width or no height draws nothing.
+    fn frame_edges(
+        &mut self,
+        at: (u16, u16),
+        to: (u16, u16),
+        top_pattern: u16,
+        bottom_pattern: u16,
+        left_pattern: u16,
+        right_pattern: u16
+    ) {
+        let mut left = at.0;
+        let mut top = at.1;
+        let mut right = to.0;
+        let mut bottom = to.1;
+
+        if left > right {
+            mem::swap(&mut left, &mut right);
+        }
+
+        if top > bottom {
+            mem::swap(&mut top, &mut bottom);
+        }
+
+        if (left == right) || (top == bottom) {
+            return;  // no interior, and so no edges either.
+        }
+
+        self.hline((left, top), right, top_pattern);
+        self.hline((left, bottom - 1), right, bottom_pattern);
+        self.vline((left, top), bottom, left_pattern);
+        self.vline((right - 1, top), bottom, right_pattern);
+    }
+
+    /// As with `frame`, but the border is `thickness` pixels wide on every side.
+    /// The pattern stays aligned to the surface, so it runs continuously across the band.
+    /// A border too thick to leave any interior fills the rectangle solid.
+    fn thick_frame(&mut self, at: (u16, u16), to: (u16, u16), thickness: u16, pattern: u16) {
+        let left = min(at.0, to.0);
+        let top = min(at.1, to.1);
+        let right = max(at.0, to.0);
+        let bottom = max(at.1, to.1);
+
+        for y in top..bottom {
+            if (y - top < thickness) || (bottom - y <= thickness) {
+                self.hline((left, y), right, pattern);
+            }
+            else {
+                self.hline((left, y), min(left.saturating_add(thickness), right), pattern);
+                self.hline((max(right.saturating_sub(thickness), left), y), right, pattern);
+            }
+        }
+    }
+
+    /// Draw an evenly dotted, one pixel wide frame starting at `at` and extending to `to`.
+    /// Beginning at the top-left corner and proceeding clockwise,
+    /// `on` pixels are set to `pen`, then `off` pixels are skipped, and so on,
+    /// with the cadence continuing unbroken around the corners.
+    /// Unlike patterned lines, the dots align with the frame, not the surface.
+    fn dotted_frame(&mut self, at: (u16, u16), to: (u16, u16), on: u8, off: u8, pen: u8) {
+        let cadence = on as usize + off as usize;
+        if on == 0 {
+            return;
+        }
+
+        for (i, &point) in perimeter(at, to).iter().enumerate() {
+            if i % cadence < on as usize {
+                self.draw_point(point, pen);
+            }
+        }
+    }
+
+    /// Draw a dashed, one pixel wide line through each of `points` in turn.
+    /// Beginning at the first point, `on_px` pixels are set to `pen`,
+    /// then `off_px` pixels are skipped, and so on,
+    /// with the cadence continuing unbroken from one segment into the next.
+    /// Each vertex is visited once, and if the last point repeats the first,
+    /// the polyline closes without revisiting its starting pixel.
+    fn styled_polyline(&mut self, points: &[(u16, u16)], on_px: u8, off_px: u8, pen: u8) {
+        let cadence = on_px as usize + off_px as usize;
+        if (on_px == 0) || points.is_empty() {
+            return;
+        }
+
+        let mut path = vec![points[0]];
+        for segment in points.windows(2) {
+            path.extend(line_points(segment[0], segment[1]).into_iter().skip(1));
+        }
+        if (path.len() > 1) && (path[path.len() - 1] == path[0]) {
+            path.pop();
+        }
+
+        for (i, &point) in path.iter().enumerate() {
+            if i % cadence < on_px as usize {
+                self.draw_point(point, pen);
+            }
+        }
+    }
+
+    /// Draw a grid of lines within the rectangle from `at` to `to`,
+    /// using the supplied line pattern.
+    /// Vertical lines fall every `step.0` pixels to the right of `at`,
+    /// and horizontal lines every `step.1` pixels below it,
+    /// starting with lines along the left and top edges.
+    /// A step of zero suppresses that axis's lines entirely.
+    fn grid(&mut self, at: (u16, u16), to: (u16, u16), step: (u16, u16), pattern: u16) {
+        let left = min(at.0, to.0);
+        let top = min(at.1, to.1);
+        let right = max(at.0, to.0);
+        let bottom = max(at.1, to.1);
+
+        if step.0 > 0 {
+            let mut x = left as u32;
+            while x < right as u32 {
+                self.vline((x as u16, top), bottom, pattern);
+                x += step.0 as u32;
+            }
+        }
+
+        if step.1 > 0 {
+            let mut y = top as u32;
+            while y < bottom as u32 {
+                self.hline((left, y as u16), right, pattern);
+                y += step.1 as u32;
+            }
+        }
+    }
+
+    /// Draw a crosshair centered on `at`, using the supplied line pattern.
+    /// Each of the horizontal and vertical lines is `2*size+1` pixels long,
+    /// so a size of 0 draws only the center pixel.
+    /// Arms falling off the edges of the surface are clipped.
+    fn crosshair(&mut self, at: (u16, u16), size: u16, pattern: u16) {
+        let (x, y) = at;
+
+        self.hline((x.saturating_sub(size), y), x.saturating_add(size).saturating_add(1), pattern);
+        self.vline((x, y.saturating_sub(size)), y.saturating_add(size).saturating_add(1), pattern);
+    }
 
     /// Invert a horizontal line.
     fn invert_line(&mut self, at: (u16, u16), to: u16);
@@ -87,10 +511,20 @@ pub trait VDI {
     /// Invert a rectangle.
     fn invert_rect(&mut self, at: (u16, u16), to: (u16, u16));
 
+    /// Invert the pixels of the rectangle at `at`, of size `dimensions`,
+    /// wherever the corresponding bit of a mask is set, leaving the rest alone.
+    /// The mask is laid out as the source bitmap of `copy_rect`, `mask_width` pixels wide,
+    /// and clips the same way.
+    /// Inverting twice through the same mask restores the original pixels.
+    fn invert_masked(&mut self, mask_bits: &[u16], mask_width: usize, at: (u16, u16), dimensions: (u16, u16)) {
+        self.copy_rect((0, 0), mask_width, mask_bits, at, dimensions, raster_op_bits(RasterOp::Xor));
+    }
+
     /// Copy a single row of pixels from a source bitmap into the VDI surface.
     ///
     /// `from` specifies where, in the source bitmap, to start reading bits to
-    /// copy.  These coordinates cannot exceed the boundaries of the source bitmap.
+    /// copy.  Pixels outside the boundaries of the source bitmap, whether past
+    /// `src_width` or past the end of `from_bits`, are not copied.
     /// `src_width` specifies how wide the bitmap is in pixels.
     /// `from_bits` tells where to find the vector of `u16`s containing the bitmap
     /// itself.  Each row of `u16`s are just big enough to hold `src_width` pixels.
@@ -112,6 +546,7 @@ pub trait VDI {
     ///
     /// where **S** refers to the source (bitmap) pixel,
     /// and **D** refers to the corresponding destination (VDI) pixel.
+    /// `raster_op_bits` answers this value for common operations.
     fn copy_line(
         &mut self,
         from: (u16, u16),
@@ -137,7 +572,8 @@ pub trait VDI {
     /// Copy a rectangular arrangement of pixels from a source bitmap into the VDI surface.
     ///
     /// `from` specifies where, in the source bitmap, to start reading bits to
-    /// copy.  These coordinates cannot exceed the boundaries of the source bitmap.
+    /// copy.  Pixels outside the boundaries of the source bitmap, whether past
+    /// `src_width` or past the end of `from_bits`, are not copied.
     /// `src_width` specifies how wide the bitmap is in pixels.
     /// `from_bits` tells where to find the vector of `u16`s containing the bitmap
     /// itself.  Each row of `u16`s are just big enough to hold `src_width` pixels.
@@ -181,12 +617,370 @@ pub trait VDI {
         dimensions: (u16, u16),
         function: u8
     );
+
+    /// Converts a region of an 8-bit grayscale image to black and white,
+    /// using Floyd-Steinberg error diffusion, and draws it on the VDI surface.
+    ///
+    /// `gray_bits` holds one byte per pixel, `src_width` pixels per row;
+    /// values at or above the surface's `threshold` lean towards white.
+    /// `from` and `dimensions` select the region of the source to convert,
+    /// and `to` places it on the surface.
+    ///
+    /// Quantization error diffuses across the whole selected region,
+    /// even the parts which fall off the edges of the surface,
+    /// so clipping doesn't change how the visible part looks.
+    fn copy_rect_error_diffused(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        gray_bits: &[u8],
+        to: (u16, u16),
+        dimensions: (u16, u16)
+    ) {
+        if src_width == 0 {
+            return;
+        }
+
+        // Clamp the region to the source image.
+        let src_height = gray_bits.len() / src_width;
+        let left = min(from.0 as usize, src_width);
+        let top = min(from.1 as usize, src_height);
+        let width = min(dimensions.0 as usize, src_width - left);
+        let height = min(dimensions.1 as usize, src_height - top);
+
+        // Scratch buffer, with room to spill error past the right and bottom edges.
+        let span = width + 2;
+        let mut scratch : Vec<i32> = vec![0; span * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                scratch[y * span + x + 1] = gray_bits[(top + y) * src_width + left + x] as i32;
+            }
+        }
+
+        let threshold = self.threshold() as i32;
+        for y in 0..height {
+            for x in 0..width {
+                let here = y * span + x + 1;
+                let old = scratch[here];
+                let new = if old >= threshold { 255 } else { 0 };
+                let error = old - new;
+
+                scratch[here + 1] += error * 7 / 16;
+                scratch[here + span - 1] += error * 3 / 16;
+                scratch[here + span] += error * 5 / 16;
+                scratch[here + span + 1] += error / 16;
+
+                // Points past the end of the coordinate space can't be on the surface.
+                let (px, py) = (to.0 as i32 + x as i32, to.1 as i32 + y as i32);
+                if (px <= 0xFFFF) && (py <= 0xFFFF) {
+                    self.draw_point((px as u16, py as u16), new as u8);
+                }
+            }
+        }
+    }
+
+    /// Draws a dithered silhouette of a source bitmap,
+    /// such as a drop shadow to go beneath an icon.
+    /// Wherever a source bit is set, `pen` is written to the destination,
+    /// but only at surface pixels where `(x ^ y) & 1 == 0`;
+    /// all other destination pixels are left alone.
+    ///
+    /// The source bitmap and clipping work just as they do for `copy_rect`.
+    fn copy_rect_stippled(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        pen: u8
+    );
+
+    /// As with `copy_rect`, but shrinks the source to half its size in each direction,
+    /// such as to draw a glyph at half size.
+    /// Each destination pixel stands for a 2 by 2 block of source pixels,
+    /// and is set if any of them is, so one pixel wide strokes survive
+    /// rather than vanishing between samples.
+    ///
+    /// `from` locates the top-left corner of the source region, in source pixels,
+    /// while `dimensions` gives the size of the shrunken image drawn at `to`.
+    fn copy_rect_thinned(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        function: u8
+    ) {
+        let (width, height) = (dimensions.0 as usize, dimensions.1 as usize);
+        let words = (width + 15) / 16;
+        let mut thinned = vec![0u16; words * height];
+
+        for y in 0..height {
+            for x in 0..width {
+                let mut hit = false;
+                for dy in 0..2 {
+                    for dx in 0..2 {
+                        let sx = from.0 as usize + x * 2 + dx;
+                        let sy = from.1 as usize + y * 2 + dy;
+                        if (sx <= 0xFFFF) && (sy <= 0xFFFF) && bitmap_hit(from_bits, src_width, (sx as u16, sy as u16)) {
+                            hit = true;
+                        }
+                    }
+                }
+                if hit {
+                    thinned[y * words + x / 16] |= 1 << (x & 15);
+                }
+            }
+        }
+
+        self.copy_rect((0, 0), width, &thinned, to, dimensions, function);
+    }
+
+    /// Composites a two-plane cursor, such as a mouse pointer, onto the surface in one pass.
+    /// Each destination pixel is first ORed with its bit in `ior_bits`,
+    /// then XORed with its bit in `xor_bits`,
+    /// just as drawing `ior_bits` with `RasterOp::Or` and then `xor_bits` with `RasterOp::Xor`
+    /// through `copy_rect` would.
+    ///
+    /// Both planes share `src_width` and the little-endian layout of `copy_rect`'s source bitmap,
+    /// and clipping works just as it does for `copy_rect`.
+    fn copy_rect_cursor(
+        &mut self,
+        ior_bits: &[u16],
+        xor_bits: &[u16],
+        src_width: usize,
+        from: (u16, u16),
+        to: (u16, u16),
+        dimensions: (u16, u16)
+    );
+}
+
+
+/// Answers whether the pixel at `at` in a little-endian source bitmap
+/// (as taken by `VDI::copy_line`) is set,
+/// such as to test whether a click landed on an irregularly shaped icon
+/// rather than merely within its bounding box.
+/// Pixels outside the bitmap read as clear.
+pub fn bitmap_hit(bits: &[u16], src_width: usize, at: (u16, u16)) -> bool {
+    let (x, y) = (at.0 as usize, at.1 as usize);
+    if x >= src_width {
+        return false;
+    }
+
+    let offset = y * ((src_width + 15) / 16) + x / 16;
+    if offset >= bits.len() {
+        return false;
+    }
+
+    (bits[offset] >> (x & 15)) & 1 != 0
+}
+
+
+/// Answers the pixels around the edge of the rectangle from `at` to `to`,
+/// each exactly once, clockwise from the top-left corner.
+fn perimeter(at: (u16, u16), to: (u16, u16)) -> Vec<(u16, u16)> {
+    let left = min(at.0, to.0);
+    let top = min(at.1, to.1);
+    let right = max(at.0, to.0);
+    let bottom = max(at.1, to.1);
+    let mut points = Vec::new();
+
+    if (left == right) || (top == bottom) {
+        return points;
+    }
+
+    for x in left..right {
+        points.push((x, top));
+    }
+    for y in (top + 1)..bottom {
+        points.push((right - 1, y));
+    }
+    if bottom - top > 1 {
+        for x in (left..(right - 1)).rev() {
+            points.push((x, bottom - 1));
+        }
+    }
+    if right - left > 1 {
+        for y in ((top + 1)..(bottom - 1)).rev() {
+            points.push((left, y));
+        }
+    }
+
+    points
+}
+
+
+/// Color-expands a backbuffer of the given dimensions into a streaming texture
+/// with 32 bits per pixel.
+fn expand_backbuffer(backbuf: &[u8], dimensions: (u16, u16), colors: &[u32; 256], t: &mut render::Texture) -> result::Result<(), String> {
+    t.with_lock(None, |bits: &mut [u8], span: usize| {
+        expand_pixels(backbuf, dimensions, colors, bits, span);
+    })
+}
+
+
+/// Color-expands a backbuffer of the given dimensions into locked texture memory,
+/// 32 bits per pixel, with rows `span` bytes apart.
+/// Each backbuffer value becomes its entry in `colors`.
+fn expand_pixels(backbuf: &[u8], dimensions: (u16, u16), colors: &[u32; 256], bits: &mut [u8], span: usize) {
+    let (width, height) = (dimensions.0 as usize, dimensions.1 as usize);
+    let mut source_offset = 0;
+    let mut dest_offset = 0;
+
+    for _ in 0..height {
+        for x in 0..width {
+            let word = colors[backbuf[source_offset] as usize];
+            source_offset += 1;
+
+            store_pixel(bits, dest_offset + x * 4, word);
+        }
+        dest_offset += span;
+    }
+}
+
+
+/// Stores a packed 32-bit pixel at `offset` in the host's byte order,
+/// which is how SDL2 lays out its packed pixel formats in memory.
+fn store_pixel(bits: &mut [u8], offset: usize, word: u32) {
+    let bytes = [word as u8, (word >> 8) as u8, (word >> 16) as u8, (word >> 24) as u8];
+
+    for i in 0..4 {
+        bits[offset + i] = if cfg!(target_endian = "little") { bytes[i] } else { bytes[3 - i] };
+    }
+}
+
+
+/// Answers the packed 32-bit pixel each backbuffer value color-expands into when committed.
+///
+/// The surface is monochrome, so every channel of a pixel, alpha included, carries the
+/// backbuffer value unchanged.
+/// That packs the same way in every 32-bit format `SDL2VdiBuilder` accepts,
+/// so one table serves them all.
+pub fn color_table() -> [u32; 256] {
+    let mut colors = [0u32; 256];
+
+    for pen in 0..256 {
+        colors[pen] = (pen as u32) * 0x01010101;
+    }
+
+    colors
+}
+
+
+/// Answers the pixels of a straight line from `from` to `to`, both included,
+/// in order, as chosen by Bresenham's algorithm.
+fn line_points(from: (u16, u16), to: (u16, u16)) -> Vec<(u16, u16)> {
+    let (mut x, mut y) = (from.0 as i32, from.1 as i32);
+    let (x1, y1) = (to.0 as i32, to.1 as i32);
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut error = dx + dy;
+    let mut points = Vec::new();
+
+    loop {
+        points.push((x as u16, y as u16));
+        if (x == x1) && (y == y1) {
+            return points;
+        }
+
+        let e2 = 2 * error;
+        if e2 >= dy {
+            error += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            error += dx;
+            y += sy;
+        }
+    }
+}
+
+
+/// Panics unless two snapshots (see `VDI::snapshot`) hold identical pixels,
+/// reporting the offset of the first pixel which differs.
+pub fn assert_snapshots_eq(a: &[u8], b: &[u8]) {
+    assert_eq!(a.len(), b.len(), "Snapshots differ in size");
+
+    for offset in 0..a.len() {
+        if a[offset] != b[offset] {
+            panic!("Snapshots differ at offset {}: {} != {}", offset, a[offset], b[offset]);
+        }
+    }
+}
+
+
+/// As with `assert_snapshots_eq`, but reports the coordinates of the first pixel which differs.
+/// `width` is the width of the surface the snapshots came from.
+pub fn assert_snapshots_eq_with_width(a: &[u8], b: &[u8], width: u16) {
+    assert_eq!(a.len(), b.len(), "Snapshots differ in size");
+
+    let width = max(width, 1) as usize;
+    for offset in 0..a.len() {
+        if a[offset] != b[offset] {
+            panic!(
+                "Snapshots differ at ({}, {}): {} != {}",
+                offset % width, offset / width, a[offset], b[offset]
+            );
+        }
+    }
+}
+
+
+/// Copies a `dims`-sized region of one VDI surface onto another,
+/// such as when compositing an off-screen layer onto the display.
+/// `src_at` is in `src`'s coordinates, and `dst_at` in `dst`'s;
+/// each surface's origin applies to its own coordinates.
+/// Pixels mix according to `function`, as with `VDI::copy_line`.
+///
+/// Only the part of the region lying on the source surface is copied,
+/// and that clips again at the edges of the destination surface.
+pub fn blit_surfaces(
+    src: &VDI,
+    src_at: (u16, u16),
+    dims: (u16, u16),
+    dst: &mut VDI,
+    dst_at: (u16, u16),
+    function: u8
+) {
+    let (src_width, src_height) = src.dimensions();
+    let (ox, oy) = src.origin();
+    let left = max(src_at.0 as i32, -(ox as i32));
+    let top = max(src_at.1 as i32, -(oy as i32));
+    let right = min(src_at.0 as i32 + dims.0 as i32, src_width as i32 - ox as i32);
+    let bottom = min(src_at.1 as i32 + dims.1 as i32, src_height as i32 - oy as i32);
+
+    if (left >= right) || (top >= bottom) {
+        return;
+    }
+
+    let width = (right - left) as usize;
+    let mut row = vec![0u16; (width + 63) / 64 * 4];
+    let dst_x = min(dst_at.0 as i32 + left - src_at.0 as i32, 0xFFFF) as u16;
+
+    for y in top..bottom {
+        let mut chunk = 0;
+        while chunk < width {
+            let bits = src.get_hline_bits(((left as usize + chunk) as u16, y as u16), min(width - chunk, 64) as u16);
+            for word in 0..4 {
+                row[chunk / 16 + word] = (bits >> (word * 16)) as u16;
+            }
+            chunk += 64;
+        }
+
+        let dst_y = min(dst_at.1 as i32 + y - src_at.1 as i32, 0xFFFF) as u16;
+        dst.copy_line((0, 0), width, &row, (dst_x, dst_y), width, function);
+    }
 }
 
 
 /// This structure represents an SDL2-backed VDI surface (bluntly, a window).
 /// The window is fixed in size, emulating the frame buffer of a given size.
-/// When the window opens, the state of the frame buffer is completely undefined.
+/// When the window opens, the state of the frame buffer is completely undefined,
+/// unless it was built with `SDL2VdiBuilder::clear_on_create`.
 /// You'll need to paint the frame buffer to establish a known image.
 /// For example:
 ///
@@ -222,6 +1016,23 @@ pub struct SDL2Vdi {
     /// When invoking `commit`, this backbuffer is color-expanded into pixels
     /// that SDL2 can understand, and then submitted to SDL for rendering.
     backbuffer: Vec<u8>,
+
+    /// Offset added to all incoming coordinates; see `VDI::set_origin`.
+    origin: (i16, i16),
+
+    /// The packed pixel each backbuffer value expands into; see `color_table`.
+    colors: [u32; 256],
+
+    /// Pens at or above this value draw white; see `set_threshold`.
+    threshold: u8,
+
+    /// While a preview is open, the frame buffer as it stood when the preview began;
+    /// drawing goes to `backbuffer`, a scratch copy, meanwhile.
+    /// See `begin_preview`.
+    preview_base: Option<Vec<u8>>,
+
+    /// When `commit_at` last presented a frame, if ever.
+    last_present: Option<time::Instant>,
 }
 
 
@@ -232,23 +1043,320 @@ impl SDL2Vdi {
     /// At present, the bitmap is monochrome: 0s are black, 1s are white.
     ///
     /// width and height are measured in pixels.
+    ///
+    /// This is shorthand for `SDL2VdiBuilder::new(context, width, height, title).build()`.
     pub fn new(context: & sdl2::Sdl, width: u16, height: u16, title: & str) ->
                 result::Result<SDL2Vdi, VdiError> {
-        let total_pixels = width as usize * height as usize;
-        let mut backbuffer = Vec::with_capacity(total_pixels);
-        (&mut backbuffer).resize(total_pixels, 0);
+        SDL2VdiBuilder::new(context, width, height, title).build()
+    }
 
-        let video_subsystem = match context.video() {
-            Err(e) =>
-                return Err(VdiError::FromSdl(e)),
+    /// As with `commit`, but paced to hold a steady `target_fps` frames per second.
+    /// If the previous frame was presented less than one frame period ago,
+    /// this procedure sleeps for the remainder of that period before committing.
+    /// If the application is already running behind schedule, it never sleeps.
+    ///
+    /// Use this in animation loops instead of `commit` to avoid pinning a CPU core.
+    /// A `target_fps` of 0 disables pacing.
+    pub fn commit_at(&mut self, target_fps: u32) -> result::Result<(), VdiError> {
+        if target_fps > 0 {
+            let period = time::Duration::new(0, 1_000_000_000 / target_fps);
+            if let Some(last) = self.last_present {
+                let elapsed = last.elapsed();
+                if elapsed < period {
+                    thread::sleep(period - elapsed);
+                }
+            }
+        }
 
-            Ok(subsys) =>
-                subsys
-        };
+        self.last_present = Some(time::Instant::now());
+        self.commit()
+    }
+
+    /// As with `commit`, but stops short of presenting the frame,
+    /// leaving it on the renderer's back buffer until `present`.
+    /// Use this to draw more onto the renderer before showing everything at once.
+    pub fn commit_no_present(&mut self) -> result::Result<(), VdiError> {
+        let r = &mut self.renderer;
+        let t = &mut self.texture;
+
+        expand_backbuffer(&self.backbuffer, self.dimensions, &self.colors, t)
+        .and_then(|_| r.copy(t, None, None))
+        .map_err(|e| VdiError::FromSdl(e))
+    }
+
+    /// Shows whatever has been copied to the renderer since it was last presented,
+    /// such as by `commit_no_present`.
+    /// `commit` is `commit_no_present` followed by `present`.
+    pub fn present(&mut self) -> result::Result<(), VdiError> {
+        self.renderer.present();
+        Ok(())
+    }
+
+    /// As with `commit`, but color-expands the frame buffer into `target`,
+    /// a streaming texture belonging to the host application, and presents nothing.
+    /// This lets an application embed the surface in a larger SDL2 scene,
+    /// compositing the texture however it likes.
+    ///
+    /// `target` must be exactly as large as the surface,
+    /// with 32 bits per pixel.
+    pub fn commit_to_texture(&mut self, target: &mut render::Texture) -> result::Result<(), VdiError> {
+        let query = target.query();
+        if (query.width != self.dimensions.0 as u32) || (query.height != self.dimensions.1 as u32) {
+            return Err(VdiError::FromSdl(String::from("Texture size differs from the surface")));
+        }
+        if query.format.byte_size_per_pixel() != 4 {
+            return Err(VdiError::FromSdl(String::from("Unsupported pixel format")));
+        }
+
+        expand_backbuffer(&self.backbuffer, self.dimensions, &self.colors, target)
+        .map_err(|e| VdiError::FromSdl(e))
+    }
+
+    /// Hands `f` the frame buffer as `commit` uploads it to the display:
+    /// 32 bits per pixel, with rows a given number of bytes apart,
+    /// such as for a screen recorder to encode.
+    /// Answers whatever `f` answers.
+    ///
+    /// The pixels are expanded directly into the locked texture, so there's no extra copy.
+    /// Since SDL2 doesn't promise to keep a streaming texture's contents,
+    /// they're expanded anew from the frame buffer;
+    /// call this right after `commit` to see exactly what was presented.
+    pub fn with_pixels<R, F>(&mut self, f: F) -> result::Result<R, VdiError>
+            where F: FnOnce(&[u8], usize) -> R {
+        let backbuf = &self.backbuffer;
+        let dimensions = self.dimensions;
+        let colors = &self.colors;
+
+        self.texture.with_lock(None, |bits: &mut [u8], span: usize| {
+            expand_pixels(backbuf, dimensions, colors, bits, span);
+            f(bits, span)
+        })
+        .map_err(|e| VdiError::FromSdl(e))
+    }
+
+    /// Opens a preview, such as to show an icon being dragged,
+    /// which can be thrown away later without disturbing what's beneath it.
+    /// Until `end_preview`, drawing lands on a scratch layer laid over the frame buffer;
+    /// reading pixels and committing see the scratch layer's pixels wherever it was drawn on,
+    /// and the frame buffer's elsewhere.
+    ///
+    /// Opening a preview while one is already open does nothing.
+    pub fn begin_preview(&mut self) {
+        if self.preview_base.is_none() {
+            self.preview_base = Some(self.backbuffer.clone());
+        }
+    }
+
+    /// Discards the scratch layer opened by `begin_preview`,
+    /// restoring the frame buffer exactly as it stood when the preview began.
+    /// Nothing is displayed until the next `commit`.
+    /// Does nothing if no preview is open.
+    pub fn end_preview(&mut self) {
+        if let Some(base) = self.preview_base.take() {
+            self.backbuffer = base;
+        }
+    }
+
+    /// Writes the frame buffer to `w` verbatim, one byte per pixel, row by row,
+    /// after a four byte header holding the surface's width and height
+    /// as little-endian 16-bit numbers.
+    /// `load_raw` reads it back.
+    pub fn save_raw<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let (width, height) = self.dimensions;
+        let header = [width as u8, (width >> 8) as u8, height as u8, (height >> 8) as u8];
+
+        match w.write_all(&header) {
+            Err(e) => Err(e),
+            Ok(_) => w.write_all(&self.backbuffer),
+        }
+    }
+
+    /// Replaces the frame buffer with one written by `save_raw`.
+    /// Nothing is displayed until the next `commit`.
+    ///
+    /// Saved pixels of 128 and above load as white, and the rest as black,
+    /// so the frame buffer only ever holds 0 or 255 whatever the file holds.
+    ///
+    /// Answers an `InvalidData` error if the saved surface's dimensions differ from this one's,
+    /// or the error `r` answers if it fails or ends early;
+    /// either way, the frame buffer is left untouched.
+    pub fn load_raw<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        let mut header = [0u8; 4];
+        if let Err(e) = r.read_exact(&mut header) {
+            return Err(e);
+        }
+
+        let width = header[0] as u16 | ((header[1] as u16) << 8);
+        let height = header[2] as u16 | ((header[3] as u16) << 8);
+        if (width, height) != self.dimensions {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "Saved surface size differs from the surface"));
+        }
+
+        let mut pixels = vec![0u8; self.backbuffer.len()];
+        if let Err(e) = r.read_exact(&mut pixels) {
+            return Err(e);
+        }
+
+        for (p, &saved) in self.backbuffer.iter_mut().zip(pixels.iter()) {
+            *p = if saved >= 128 { 255 } else { 0 };
+        }
+        Ok(())
+    }
+
+    /// Packs the frame buffer into the byte layout of page-addressed monochrome
+    /// display controllers, such as those driving small LCD and OLED panels.
+    /// The surface is cut into horizontal pages `page_height` pixels tall,
+    /// top to bottom, and each page into one byte per column, left to right.
+    /// Within a byte, the topmost pixel is the most significant bit,
+    /// and white pixels are set.
+    ///
+    /// `page_height` is clamped to between 1 and 8; when fewer than 8,
+    /// the unused low bits of each byte are clear.
+    /// If the surface's height isn't a multiple of `page_height`,
+    /// the last page is padded with clear bits.
+    pub fn export_pages(&self, page_height: u16) -> Vec<u8> {
+        let page_height = max(1, min(page_height, 8)) as usize;
+        let (width, height) = (self.dimensions.0 as usize, self.dimensions.1 as usize);
+        let pages = (height + page_height - 1) / page_height;
+        let mut bytes = Vec::with_capacity(pages * width);
+
+        for page in 0..pages {
+            for x in 0..width {
+                let mut byte = 0u8;
+                for row in 0..page_height {
+                    let y = page * page_height + row;
+                    if (y < height) && (self.backbuffer[y * width + x] >= 128) {
+                        byte |= 0x80 >> row;
+                    }
+                }
+                bytes.push(byte);
+            }
+        }
+
+        bytes
+    }
+
+    /// Answers a `DrawBatch` which draws directly into this surface's frame buffer,
+    /// borrowing it once for any number of primitives.
+    pub fn begin_batch<'a>(&'a mut self) -> DrawBatch<'a> {
+        DrawBatch {
+            backbuffer: &mut self.backbuffer,
+            dimensions: self.dimensions,
+            origin:     self.origin,
+            threshold:  self.threshold,
+        }
+    }
+
+    /// Sets where pens split between black and white:
+    /// `draw_point` and `copy_rect_stippled` draw white for pens of `threshold` or more,
+    /// and black otherwise, and `copy_rect_error_diffused` rounds grays at the same point.
+    /// The default, 128, splits the range in half;
+    /// raising it darkens grayscale art drawn point by point.
+    /// A threshold of 0 makes every pen white.
+    pub fn set_threshold(&mut self, threshold: u8) {
+        self.threshold = threshold;
+    }
+}
+
+
+/// Configures and opens an `SDL2Vdi` surface.
+/// Use this instead of `SDL2Vdi::new` when you need something other than
+/// the default settings.  For example:
+///
+/// ```text
+/// let sdl = sdl2::init().unwrap();
+/// let vdi : &mut vdi::VDI =
+///     &mut vdi::SDL2VdiBuilder::new(&sdl, 640, 480, "blah")
+///         .scale(2)
+///         .pixel_format(sdl2::pixels::PixelFormatEnum::ABGR8888)
+///         .build().unwrap();
+/// ```
+pub struct SDL2VdiBuilder<'a> {
+    context:    &'a sdl2::Sdl,
+    dimensions: (u16, u16),
+    title:      &'a str,
+    scale:      u8,
+    pixel_format: pixels::PixelFormatEnum,
+    clear_on_create: bool,
+}
+
+
+impl<'a> SDL2VdiBuilder<'a> {
+    /// Start configuring a surface of the given logical width and height,
+    /// measured in pixels.
+    pub fn new(context: &'a sdl2::Sdl, width: u16, height: u16, title: &'a str) -> SDL2VdiBuilder<'a> {
+        SDL2VdiBuilder {
+            context:    context,
+            dimensions: (width, height),
+            title:      title,
+            scale:      1,
+            pixel_format: pixels::PixelFormatEnum::ARGB8888,
+            clear_on_create: false,
+        }
+    }
+
+    /// Open the window `scale` times larger than the logical surface in both axes.
+    /// The frame buffer, and therefore all drawing, remains at the logical resolution;
+    /// `commit` scales the image up using nearest-neighbor filtering,
+    /// so each logical pixel appears as a crisp `scale`x`scale` block.
+    /// A scale of 0 is treated as 1.
+    pub fn scale(&mut self, scale: u8) -> &mut SDL2VdiBuilder<'a> {
+        self.scale = if scale == 0 { 1 } else { scale };
+        self
+    }
+
+    /// Create the frame buffer's texture in `pixel_format` rather than the default, ARGB8888,
+    /// for platforms or host renderers which prefer another channel order.
+    /// Only the 32-bit RGB formats are supported; `build` fails with any other.
+    /// Since every pixel is a shade of gray, `commit` fills all four bytes alike,
+    /// so the image is the same whatever order the channels come in.
+    pub fn pixel_format(&mut self, pixel_format: pixels::PixelFormatEnum) -> &mut SDL2VdiBuilder<'a> {
+        self.pixel_format = pixel_format;
+        self
+    }
+
+    /// When `clear` is true, the surface starts out black, and is committed once,
+    /// so the window opens onto a defined image rather than whatever was in video memory.
+    /// The default is false.
+    pub fn clear_on_create(&mut self, clear: bool) -> &mut SDL2VdiBuilder<'a> {
+        self.clear_on_create = clear;
+        self
+    }
+
+    /// Open the window and create the frame buffer backing it.
+    pub fn build(&self) -> result::Result<SDL2Vdi, VdiError> {
+        let (width, height) = self.dimensions;
+        let scale = self.scale as u32;
+
+        match self.pixel_format {
+            pixels::PixelFormatEnum::ARGB8888 |
+            pixels::PixelFormatEnum::RGBA8888 |
+            pixels::PixelFormatEnum::ABGR8888 |
+            pixels::PixelFormatEnum::BGRA8888 |
+            pixels::PixelFormatEnum::RGB888 |
+            pixels::PixelFormatEnum::RGBX8888 |
+            pixels::PixelFormatEnum::BGR888 |
+            pixels::PixelFormatEnum::BGRX8888 => (),
+
+            _ =>
+                return Err(VdiError::FromSdl(String::from("Unsupported pixel format"))),
+        }
+
+        let total_pixels = width as usize * height as usize;
+        let mut backbuffer = Vec::with_capacity(total_pixels);
+        (&mut backbuffer).resize(total_pixels, 0);
+
+        let video_subsystem = match self.context.video() {
+            Err(e) =>
+                return Err(VdiError::FromSdl(e)),
+
+            Ok(subsys) =>
+                subsys
+        };
 
         let w : video::Window = match video::WindowBuilder::new(
-                    &video_subsystem, title,
-                    width as u32, height as u32
+                    &video_subsystem, self.title,
+                    width as u32 * scale, height as u32 * scale
                 )
                 .resizable()
                 .build() {
@@ -279,42 +1387,655 @@ impl SDL2Vdi {
                r
         };
 
-        let mut t : render::Texture = match (&r).create_texture(
-                pixels::PixelFormatEnum::ARGB8888,
-                render::TextureAccess::Streaming,
-                width as u32, height as u32
-        ) {
+        // SDL consults this hint when the texture is created, not when it's drawn.
+        // Nearest-neighbor keeps scaled-up pixels sharp.
+        // The hint is process-wide, so put back whatever the application had set
+        // once our texture exists; if it had set nothing, SDL's default is nearest-neighbor anyway.
+        let scale_quality = if self.scale > 1 {
+            let previous = sdl2::hint::get("SDL_RENDER_SCALE_QUALITY");
+            sdl2::hint::set("SDL_RENDER_SCALE_QUALITY", "0");
+            previous
+        }
+        else {
+            None
+        };
+
+        let texture = (&r).create_texture(
+            self.pixel_format,
+            render::TextureAccess::Streaming,
+            width as u32, height as u32
+        );
+
+        if let Some(previous) = scale_quality {
+            sdl2::hint::set("SDL_RENDER_SCALE_QUALITY", &previous);
+        }
+
+        let mut t : render::Texture = match texture {
             Err(render::TextureValueError::WidthOverflows(_)) =>
                 return Err(VdiError::FromSdl(String::from("Width overflow"))),
 
             Err(render::TextureValueError::HeightOverflows(_)) =>
                 return Err(VdiError::FromSdl(String::from("Height overflow"))),
 
-            Err(render::TextureValueError::WidthMustBeMultipleOfTwoForFormat(_, _)) =>
-                return Err(VdiError::FromSdl(String::from("Texture width must be a power of two."))),
+            Err(render::TextureValueError::WidthMustBeMultipleOfTwoForFormat(_, _)) =>
+                return Err(VdiError::FromSdl(String::from("Texture width must be a power of two."))),
+
+            Err(render::TextureValueError::SdlError(s)) =>
+                return Err(VdiError::FromSdl(s)),
+
+            Ok(t) =>
+                t,
+        };
+
+        (&mut t).set_blend_mode(render::BlendMode::None);
+
+        let mut vdi = SDL2Vdi {
+            dimensions: (width, height),
+            renderer:   r,
+            texture:    t,
+            backbuffer: backbuffer,
+            origin:     (0, 0),
+            colors:     color_table(),
+            threshold:  128,
+            preview_base: None,
+            last_present: None,
+        };
+
+        if self.clear_on_create {
+            for p in vdi.backbuffer.iter_mut() {
+                *p = 0;
+            }
+            if let Err(e) = vdi.commit() {
+                return Err(e);
+            }
+        }
+
+        Ok(vdi)
+    }
+}
+
+
+impl SDL2Vdi {
+    /// Translates a caller-supplied coordinate into surface coordinates.
+    /// The result may fall outside the surface in any direction.
+    fn translate(&self, at: (u16, u16)) -> (i32, i32) {
+        (at.0 as i32 + self.origin.0 as i32, at.1 as i32 + self.origin.1 as i32)
+    }
+}
+
+
+impl VDI for SDL2Vdi {
+    fn draw_point(&mut self, at: (u16, u16), pen: u8) {
+        self.begin_batch().draw_point(at, pen);
+    }
+
+    fn get_point(&self, at: (u16, u16)) -> u8 {
+        let (x, y) = self.translate(at);
+        if (x < 0) || (y < 0) {
+            return 0;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let (width, height) = self.dimensions;
+        let (width, height) = (width as usize, height as usize);
+
+        if (x >= width) || (y >= height) {
+            0
+        }
+        else {
+            let offset = y * width + x;
+            self.backbuffer[offset]
+        }
+    }
+
+    fn point_visible(&self, at: (u16, u16)) -> bool {
+        let (x, y) = self.translate(at);
+        let (width, height) = self.dimensions;
+
+        (x >= 0) && (y >= 0) && (x < width as i32) && (y < height as i32)
+    }
+
+    fn get_hline_bits(&self, at: (u16, u16), width: u16) -> u64 {
+        let (x, y) = self.translate(at);
+        let (surface_width, surface_height) = self.dimensions;
+
+        if (y < 0) || (y >= surface_height as i32) {
+            return 0;
+        }
+
+        let left = max(x, 0);
+        let right = min(x + min(width, 64) as i32, surface_width as i32);
+        let row = (y as usize) * (surface_width as usize);
+        let mut bits = 0u64;
+
+        for px in left..right {
+            if self.backbuffer[row + px as usize] >= 128 {
+                bits |= 1 << (px - x);
+            }
+        }
+
+        bits
+    }
+
+    fn dimensions(&self) -> (u16, u16) {
+        self.dimensions
+    }
+
+    fn set_origin(&mut self, x: i16, y: i16) {
+        self.origin = (x, y);
+    }
+
+    fn origin(&self) -> (i16, i16) {
+        self.origin
+    }
+
+    fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    fn snapshot(&self) -> Vec<u8> {
+        self.backbuffer.clone()
+    }
+
+    fn commit(&mut self) -> result::Result<(), VdiError> {
+        match self.commit_no_present() {
+            Err(e) => Err(e),
+            Ok(_) => self.present(),
+        }
+    }
+
+    fn commit_reporting(&mut self) -> result::Result<(u16, u16, u16, u16), VdiError> {
+        // commit always uploads the whole frame buffer.
+        let (width, height) = self.dimensions;
+        self.commit().map(|_| (0, 0, width, height))
+    }
+
+    fn hline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        self.begin_batch().hline(at, to, pattern);
+    }
+
+    fn hline_phased(&mut self, at: (u16, u16), to: u16, pattern: u16, phase: u8) {
+        self.begin_batch().hline_phased(at, to, pattern, phase);
+    }
+
+    fn vline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        self.begin_batch().vline(at, to, pattern);
+    }
+
+    fn rect_offset(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16], offset: (u16, u16)) {
+        let mut top = at.1;
+        let mut bottom = to.1;
+
+        if top >= bottom {
+            mem::swap(&mut top, &mut bottom);
+        }
+
+        // Patterns align with the surface, so select rows by surface coordinate.
+        // hline takes pixel x from bit (x & 15); pre-rotating each row
+        // makes that bit ((x + offset.0) & 15) of the original row instead.
+        let origin_y = self.origin.1 as i32;
+        let phase_y = origin_y + offset.1 as i32;
+        let phase_x = (offset.0 & 15) as u32;
+        for y in top..bottom {
+            let row = pattern[((y as i32 + phase_y) & 15) as usize];
+            self.hline((at.0, y), to.0, row.rotate_right(phase_x));
+        }
+    }
+
+    fn invert_line(&mut self, at: (u16, u16), to: u16) {
+        self.begin_batch().invert_line(at, to);
+    }
+
+    fn invert_rect(&mut self, at: (u16, u16), to: (u16, u16)) {
+        self.begin_batch().invert_rect(at, to);
+    }
+
+    fn copy_line(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        width: usize,
+        function: u8
+    ) {
+        self.begin_batch().copy_line(from, src_width, from_bits, to, width, function);
+    }
+
+    fn copy_line_big_endian(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        width: usize,
+        function: u8
+    ) {
+        self.begin_batch().copy_line_big_endian(from, src_width, from_bits, to, width, function);
+    }
+
+    fn copy_rect(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        function: u8
+    ) {
+        self.begin_batch().copy_rect(from, src_width, from_bits, to, dimensions, function);
+    }
+
+    fn copy_rect_big_endian(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        function: u8
+    ) {
+        self.begin_batch().copy_rect_big_endian(from, src_width, from_bits, to, dimensions, function);
+    }
+
+    fn copy_rect_stippled(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        pen: u8
+    ) {
+        self.begin_batch().copy_rect_stippled(from, src_width, from_bits, to, dimensions, pen);
+    }
+
+    fn copy_rect_cursor(
+        &mut self,
+        ior_bits: &[u16],
+        xor_bits: &[u16],
+        src_width: usize,
+        from: (u16, u16),
+        to: (u16, u16),
+        dimensions: (u16, u16)
+    ) {
+        self.begin_batch().copy_rect_cursor(ior_bits, xor_bits, src_width, from, to, dimensions);
+    }
+}
+
+
+/// A rectangular window onto another VDI surface, itself usable as a VDI surface,
+/// such as for drawing a child widget or sub-window.
+/// Coordinate (0, 0) of the viewport is its top-left corner,
+/// and nothing drawn through it can land outside of it.
+///
+/// A viewport has its own origin (see `VDI::set_origin`), independent of its parent's.
+/// Viewports nest: a viewport onto another viewport composes their positions,
+/// and is clipped to both.
+/// Patterns remain aligned to the underlying surface.
+pub struct Viewport<'a> {
+    /// The surface this viewport draws onto.
+    vdi: &'a mut VDI,
+
+    /// Where the viewport's top-left corner falls, in the parent's coordinates.
+    at: (u16, u16),
+
+    /// The size of the viewport; drawing outside of it is clipped.
+    dimensions: (u16, u16),
+
+    /// Offset added to all incoming coordinates; see `VDI::set_origin`.
+    origin: (i16, i16),
+}
+
+
+impl<'a> Viewport<'a> {
+    /// Opens a viewport onto `vdi`, with its top-left corner at `at` in `vdi`'s coordinates,
+    /// and measuring `dimensions` pixels.
+    pub fn new(vdi: &'a mut VDI, at: (u16, u16), dimensions: (u16, u16)) -> Viewport<'a> {
+        Viewport {
+            vdi:        vdi,
+            at:         at,
+            dimensions: (min(dimensions.0, 0xFFFF - at.0), min(dimensions.1, 0xFFFF - at.1)),
+            origin:     (0, 0),
+        }
+    }
+
+    /// Answers where `at` falls relative to the viewport's top-left corner.
+    fn translate(&self, at: (u16, u16)) -> (i32, i32) {
+        (at.0 as i32 + self.origin.0 as i32, at.1 as i32 + self.origin.1 as i32)
+    }
+
+    /// Answers where a point, relative to the viewport's top-left corner, falls in the parent.
+    /// The point must lie within or along the edges of the viewport.
+    fn parent(&self, x: i32, y: i32) -> (u16, u16) {
+        ((self.at.0 as i32 + x) as u16, (self.at.1 as i32 + y) as u16)
+    }
+
+    fn contains(&self, x: i32, y: i32) -> bool {
+        (x >= 0) && (y >= 0) && (x < self.dimensions.0 as i32) && (y < self.dimensions.1 as i32)
+    }
+
+    /// Answers the part of the span between `a` and `b`, in either order,
+    /// which falls within `[0, limit)`, if any.
+    fn clip_span(a: i32, b: i32, limit: u16) -> Option<(i32, i32)> {
+        let left = max(min(a, b), 0);
+        let right = min(max(a, b), limit as i32);
+
+        if left < right {
+            Some((left, right))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Answers the part of the rectangle between the corners `at` and `to`
+    /// which falls within the viewport, if any, as its top-left and bottom-right corners
+    /// relative to the viewport's top-left corner.
+    fn clip_rect(&self, at: (u16, u16), to: (u16, u16)) -> Option<((i32, i32), (i32, i32))> {
+        let (x1, y1) = self.translate(at);
+        let (x2, y2) = self.translate(to);
+
+        match (Viewport::clip_span(x1, x2, self.dimensions.0), Viewport::clip_span(y1, y2, self.dimensions.1)) {
+            (Some((left, right)), Some((top, bottom))) => Some(((left, top), (right, bottom))),
+            _ => None,
+        }
+    }
+
+    /// Clips a copy of a `dimensions`-sized source region at `from` to the destination `to`,
+    /// answering the source position, parent destination, and size of what remains.
+    fn clip_copy(&self, from: (u16, u16), to: (u16, u16), dimensions: (u16, u16)) ->
+            Option<((u16, u16), (u16, u16), (u16, u16))> {
+        let (x, y) = self.translate(to);
+        let right = x + dimensions.0 as i32;
+        let bottom = y + dimensions.1 as i32;
+
+        let (left, right) = match Viewport::clip_span(x, right, self.dimensions.0) {
+            None => return None,
+            Some(span) => span,
+        };
+        let (top, bottom) = match Viewport::clip_span(y, bottom, self.dimensions.1) {
+            None => return None,
+            Some(span) => span,
+        };
+
+        let src_x = from.0 as i32 + left - x;
+        let src_y = from.1 as i32 + top - y;
+        if (src_x > 0xFFFF) || (src_y > 0xFFFF) {
+            return None;
+        }
+
+        Some((
+            (src_x as u16, src_y as u16),
+            self.parent(left, top),
+            ((right - left) as u16, (bottom - top) as u16),
+        ))
+    }
+}
+
+
+impl<'a> VDI for Viewport<'a> {
+    fn draw_point(&mut self, at: (u16, u16), pen: u8) {
+        let (x, y) = self.translate(at);
+        if self.contains(x, y) {
+            let at = self.parent(x, y);
+            self.vdi.draw_point(at, pen);
+        }
+    }
+
+    fn get_point(&self, at: (u16, u16)) -> u8 {
+        let (x, y) = self.translate(at);
+        if self.contains(x, y) {
+            self.vdi.get_point(self.parent(x, y))
+        }
+        else {
+            0
+        }
+    }
+
+    fn point_visible(&self, at: (u16, u16)) -> bool {
+        let (x, y) = self.translate(at);
+        self.contains(x, y) && self.vdi.point_visible(self.parent(x, y))
+    }
+
+    fn get_hline_bits(&self, at: (u16, u16), width: u16) -> u64 {
+        let (x, y) = self.translate(at);
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return 0;
+        }
+
+        match Viewport::clip_span(x, x + min(width, 64) as i32, self.dimensions.0) {
+            None => 0,
+            Some((left, right)) =>
+                self.vdi.get_hline_bits(self.parent(left, y), (right - left) as u16) << (left - x),
+        }
+    }
+
+    fn dimensions(&self) -> (u16, u16) {
+        self.dimensions
+    }
+
+    fn set_origin(&mut self, x: i16, y: i16) {
+        self.origin = (x, y);
+    }
+
+    fn origin(&self) -> (i16, i16) {
+        self.origin
+    }
+
+    fn threshold(&self) -> u8 {
+        self.vdi.threshold()
+    }
+
+    fn snapshot(&self) -> Vec<u8> {
+        let (width, height) = self.dimensions;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                pixels.push(self.vdi.get_point(self.parent(x, y)));
+            }
+        }
+
+        pixels
+    }
+
+    fn commit(&mut self) -> result::Result<(), VdiError> {
+        self.vdi.commit()
+    }
+
+    fn commit_reporting(&mut self) -> result::Result<(u16, u16, u16, u16), VdiError> {
+        self.vdi.commit_reporting()
+    }
+
+    fn hline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        let (x, y) = self.translate(at);
+        let (to, _) = self.translate((to, 0));
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return;
+        }
+
+        if let Some((left, right)) = Viewport::clip_span(x, to, self.dimensions.0) {
+            let at = self.parent(left, y);
+            let to = self.parent(right, y).0;
+            self.vdi.hline(at, to, pattern);
+        }
+    }
+
+    fn hline_phased(&mut self, at: (u16, u16), to: u16, pattern: u16, phase: u8) {
+        let (x, y) = self.translate(at);
+        let (to, _) = self.translate((to, 0));
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return;
+        }
+
+        if let Some((left, right)) = Viewport::clip_span(x, to, self.dimensions.0) {
+            // Carry the phase past any pixels clipped off the left end.
+            let phase = ((phase as i32 + left - min(x, to)) & 15) as u8;
+            let at = self.parent(left, y);
+            let to = self.parent(right, y).0;
+            self.vdi.hline_phased(at, to, pattern, phase);
+        }
+    }
+
+    fn vline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        let (x, y) = self.translate(at);
+        let (_, to) = self.translate((0, to));
+        if (x < 0) || (x >= self.dimensions.0 as i32) {
+            return;
+        }
+
+        if let Some((top, bottom)) = Viewport::clip_span(y, to, self.dimensions.1) {
+            let at = self.parent(x, top);
+            let to = self.parent(x, bottom).1;
+            self.vdi.vline(at, to, pattern);
+        }
+    }
+
+    fn rect_offset(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16], offset: (u16, u16)) {
+        if let Some(((left, top), (right, bottom))) = self.clip_rect(at, to) {
+            let at = self.parent(left, top);
+            let to = self.parent(right, bottom);
+            self.vdi.rect_offset(at, to, pattern, offset);
+        }
+    }
+
+    fn invert_line(&mut self, at: (u16, u16), to: u16) {
+        let (x, y) = self.translate(at);
+        let (to, _) = self.translate((to, 0));
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return;
+        }
+
+        if let Some((left, right)) = Viewport::clip_span(x, to, self.dimensions.0) {
+            let at = self.parent(left, y);
+            let to = self.parent(right, y).0;
+            self.vdi.invert_line(at, to);
+        }
+    }
+
+    fn invert_rect(&mut self, at: (u16, u16), to: (u16, u16)) {
+        if let Some(((left, top), (right, bottom))) = self.clip_rect(at, to) {
+            let at = self.parent(left, top);
+            let to = self.parent(right, bottom);
+            self.vdi.invert_rect(at, to);
+        }
+    }
+
+    fn copy_line(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        width: usize,
+        function: u8
+    ) {
+        let width = min(width, 0xFFFF) as u16;
+        if let Some((from, to, (width, _))) = self.clip_copy(from, to, (width, 1)) {
+            self.vdi.copy_line(from, src_width, from_bits, to, width as usize, function);
+        }
+    }
+
+    fn copy_line_big_endian(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        width: usize,
+        function: u8
+    ) {
+        let width = min(width, 0xFFFF) as u16;
+        if let Some((from, to, (width, _))) = self.clip_copy(from, to, (width, 1)) {
+            self.vdi.copy_line_big_endian(from, src_width, from_bits, to, width as usize, function);
+        }
+    }
+
+    fn copy_rect(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        function: u8
+    ) {
+        if let Some((from, to, dimensions)) = self.clip_copy(from, to, dimensions) {
+            self.vdi.copy_rect(from, src_width, from_bits, to, dimensions, function);
+        }
+    }
+
+    fn copy_rect_big_endian(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        function: u8
+    ) {
+        if let Some((from, to, dimensions)) = self.clip_copy(from, to, dimensions) {
+            self.vdi.copy_rect_big_endian(from, src_width, from_bits, to, dimensions, function);
+        }
+    }
 
-            Err(render::TextureValueError::SdlError(s)) =>
-                return Err(VdiError::FromSdl(s)),
+    fn copy_rect_stippled(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        pen: u8
+    ) {
+        if let Some((from, to, dimensions)) = self.clip_copy(from, to, dimensions) {
+            self.vdi.copy_rect_stippled(from, src_width, from_bits, to, dimensions, pen);
+        }
+    }
 
-            Ok(t) =>
-                t,
-        };
+    fn copy_rect_cursor(
+        &mut self,
+        ior_bits: &[u16],
+        xor_bits: &[u16],
+        src_width: usize,
+        from: (u16, u16),
+        to: (u16, u16),
+        dimensions: (u16, u16)
+    ) {
+        if let Some((from, to, dimensions)) = self.clip_copy(from, to, dimensions) {
+            self.vdi.copy_rect_cursor(ior_bits, xor_bits, src_width, from, to, dimensions);
+        }
+    }
+}
 
-        (&mut t).set_blend_mode(render::BlendMode::None);
 
-        Ok(SDL2Vdi {
-            dimensions: (width, height),
-            renderer:   r,
-            texture:    t,
-            backbuffer: backbuffer,
-        })
-    }
+/// Draws straight into an `SDL2Vdi`'s frame buffer, which it borrows for as long as it lives,
+/// sparing heavy drawing the overhead of going through the `VDI` trait call by call.
+/// Get one from `SDL2Vdi::begin_batch`.
+/// The surface's origin applies, and drawing clips to the surface's edges,
+/// just as for the surface's own primitives; indeed, those draw through a batch of their own.
+/// A batch offers the primitives an `SDL2Vdi` implements itself:
+/// points, lines, rectangles and frames, inversion, and the blits.
+/// The `VDI` trait's provided methods, built atop these, are not repeated here.
+///
+/// Once the batch is dropped, or ended with `end_batch`, the surface can be committed.
+pub struct DrawBatch<'a> {
+    backbuffer: &'a mut [u8],
+    dimensions: (u16, u16),
+    origin:     (i16, i16),
+    threshold:  u8,
 }
 
 
-impl VDI for SDL2Vdi {
-    fn draw_point(&mut self, at: (u16, u16), pen: u8) {
-        let (x, y) = at;
+impl<'a> DrawBatch<'a> {
+    /// As with `VDI::draw_point`.
+    pub fn draw_point(&mut self, at: (u16, u16), pen: u8) {
+        let (x, y) = self.translate(at);
+        if (x < 0) || (y < 0) {
+            return;
+        }
+
         let (x, y) = (x as usize, y as usize);
         let (width, height) = self.dimensions;
         let (width, height) = (width as usize, height as usize);
@@ -324,13 +2045,18 @@ impl VDI for SDL2Vdi {
             return;
         }
 
-        let p = if pen >= 128 { 255 } else { 0 };
+        let p = if pen >= self.threshold { 255 } else { 0 };
 
         backbuf[(y * width + x) as usize] = p;
     }
 
-    fn get_point(&self, at: (u16, u16)) -> u8 {
-        let (x, y) = at;
+    /// As with `VDI::get_point`.
+    pub fn get_point(&self, at: (u16, u16)) -> u8 {
+        let (x, y) = self.translate(at);
+        if (x < 0) || (y < 0) {
+            return 0;
+        }
+
         let (x, y) = (x as usize, y as usize);
         let (width, height) = self.dimensions;
         let (width, height) = (width as usize, height as usize);
@@ -344,77 +2070,22 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn commit(&mut self) -> result::Result<(), VdiError> {
-        let (width, height) = self.dimensions;
-        let (width, height) = (width as usize, height as usize);
-        let backbuf = &mut self.backbuffer; 
-    let r = &mut self.renderer;
-        let t = &mut self.texture;
-
-        t.with_lock(None, |bits: &mut [u8], span: usize| {
-            let mut source_offset = 0;
-            let mut dest_offset = 0;
-
-            for _ in 0..height {
-                for x in 0..width {
-                    let pen = backbuf[source_offset];
-                    source_offset += 1;
-
-                    let x4 = dest_offset + x * 4;
-                    bits[x4+0] = pen;
-                    bits[x4+1] = pen;
-                    bits[x4+2] = pen;
-                    bits[x4+3] = pen;
-                }
-                dest_offset += span;
-            }
-        }).and_then(|_| r.copy(t, None, None))
-        .map_err(|e| VdiError::FromSdl(e))
-        .and_then(|_| -> result::Result<(), VdiError> {
-            r.present();
-            Ok(())
-        })
+    /// As with `VDI::hline`.
+    pub fn hline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        self.hline_from_phase(at, to, pattern, None);
     }
 
-    fn hline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
-        let (left, y) = at;
-        let mut left = left as usize;
-        let mut right = to as usize;
-        let y = y as usize;
-        let backbuf = &mut self.backbuffer;
-
-        let width = self.dimensions.0 as usize;
-
-        if left >= right {
-            mem::swap(&mut left, &mut right);
-        }
-
-        if left >= width {
-            left = width;
-        }
-
-        if right >= width {
-            right = width;
-        }
-
-        let mut offset = y * width + left;
-        let mut p = pattern.rotate_right((left & 15) as u32);
-
-        for _ in left..right {
-            backbuf[offset] = if (p & 1) != 0 { 255 } else { 0 };
-            p = p.rotate_right(1);
-            offset += 1;
-        }
+    /// As with `VDI::hline_phased`.
+    pub fn hline_phased(&mut self, at: (u16, u16), to: u16, pattern: u16, phase: u8) {
+        self.hline_from_phase(at, to, pattern, Some(phase));
     }
 
-    fn vline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
-        let left = at.0 as usize;
-        let mut top = at.1 as usize;
-        let mut bottom = to as usize;
-        let width = self.dimensions.0 as usize;
-        let height = self.dimensions.1 as usize;
+    /// As with `VDI::vline`.
+    pub fn vline(&mut self, at: (u16, u16), to: u16, pattern: u16) {
+        let (left, mut top) = self.translate(at);
+        let mut bottom = self.translate((0, to)).1;
 
-        if left >= width {
+        if (left < 0) || (left >= self.dimensions.0 as i32) {
             return; // off surface; nothing to draw.
         }
 
@@ -422,15 +2093,11 @@ impl VDI for SDL2Vdi {
             mem::swap(&mut top, &mut bottom);
         }
 
-        if top >= height {
-            top = height;
-        }
-
-        if bottom >= height {
-            bottom = height;
-        }
-
-        let mut backbuf = &mut self.backbuffer;
+        let top = self.clip_y(top);
+        let bottom = self.clip_y(bottom);
+        let left = left as usize;
+        let width = self.dimensions.0 as usize;
+        let backbuf = &mut self.backbuffer;
         let mut offset = top * width + left;
         let mut p = pattern.rotate_right((top & 15) as u32);
 
@@ -441,58 +2108,36 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn rect(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16]) {
-        let mut top = at.1;
-        let mut bottom = to.1;
-
-        if top >= bottom {
-            mem::swap(&mut top, &mut bottom);
-        }
+    /// As with `VDI::rect`.
+    pub fn rect(&mut self, at: (u16, u16), to: (u16, u16), pattern: &[u16; 16]) {
+        let top = min(at.1, to.1);
+        let bottom = max(at.1, to.1);
+        let origin_y = self.origin.1 as i32;
 
         for y in top..bottom {
-            self.hline((at.0, y), to.0, pattern[(y & 15) as usize]);
+            let row = pattern[((y as i32 + origin_y) & 15) as usize];
+            self.hline((at.0, y), to.0, row);
         }
     }
 
-    fn frame(&mut self, at: (u16, u16), to: (u16, u16), pattern: u16) {
-        let mut left = at.0;
-        let mut top = at.1;
-        let mut right = to.0;
-        let mut bottom = to.1;
-
-        if left > right {
-            mem::swap(&mut left, &mut right);
-        }
+    /// As with `VDI::invert_line`.
+    pub fn invert_line(&mut self, at: (u16, u16), to: u16) {
+        let (mut left, y) = self.translate(at);
+        let mut right = self.translate((to, 0)).0;
 
-        if top > bottom {
-            mem::swap(&mut top, &mut bottom);
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return; // off surface; nothing to draw.
         }
 
-        self.hline((left, top), right, pattern);
-        self.hline((left, bottom - 1), right, pattern);
-        self.vline((left, top), bottom, pattern);
-        self.vline((right-1, top), bottom, pattern);
-    }
-
-    fn invert_line(&mut self, at: (u16, u16), to: u16) {
-        let mut left = at.0 as usize;
-        let y = at.1 as usize;
-        let mut right = to as usize;
-        let backbuf = &mut self.backbuffer;
-
-        let width = self.dimensions.0 as usize;
-
         if left >= right {
             mem::swap(&mut left, &mut right);
         }
 
-        if left >= width {
-            left = width;
-        }
-
-        if right >= width {
-            right = width;
-        }
+        let left = self.clip_x(left);
+        let right = self.clip_x(right);
+        let y = y as usize;
+        let width = self.dimensions.0 as usize;
+        let backbuf = &mut self.backbuffer;
 
         let mut offset = y * width + left;
 
@@ -502,7 +2147,8 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn invert_rect(&mut self, at: (u16, u16), to: (u16, u16)) {
+    /// As with `VDI::invert_rect`.
+    pub fn invert_rect(&mut self, at: (u16, u16), to: (u16, u16)) {
         let mut top = at.1;
         let mut bottom = to.1;
 
@@ -515,7 +2161,25 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn copy_line(
+    /// As with `VDI::frame`.
+    pub fn frame(&mut self, at: (u16, u16), to: (u16, u16), pattern: u16) {
+        let left = min(at.0, to.0);
+        let top = min(at.1, to.1);
+        let right = max(at.0, to.0);
+        let bottom = max(at.1, to.1);
+
+        if (left == right) || (top == bottom) {
+            return;  // no interior, and so no edges either.
+        }
+
+        self.hline((left, top), right, pattern);
+        self.hline((left, bottom - 1), right, pattern);
+        self.vline((left, top), bottom, pattern);
+        self.vline((right - 1, top), bottom, pattern);
+    }
+
+    /// As with `VDI::copy_line`.
+    pub fn copy_line(
         &mut self,
         from: (u16, u16),
         src_width: usize,
@@ -524,6 +2188,11 @@ impl VDI for SDL2Vdi {
         width: usize,
         function: u8
     ) {
+        let (from, to, width) = match self.clip_copy_line(from, to, width) {
+            None => return,  // off surface; nothing to draw.
+            Some(clipped) => clipped,
+        };
+
         // First, expand the pen lookup table implied by `function`
         // into something we can index conveniently.
         // Index bit 1 maps to the source bit, while bit 0 maps to the destination bit.
@@ -537,10 +2206,15 @@ impl VDI for SDL2Vdi {
         let src_left = from.0 as usize;
         let src_width_u16 = (src_width + 15) / 16;
         let mut soffset = ((from.1 as usize) * src_width_u16) + (src_left / 16);
+        let largest_offset = from_bits.len();
+
+        if (src_left >= src_width) || (soffset >= largest_offset) {
+            return;  // nothing to read from the source; nothing to draw.
+        }
+
         let mut ix = src_left & 15;
         let mut src_word = from_bits[soffset] >> ix;
         let src_width_adjusted = min(width, src_width - src_left);
-        let largest_offset = from_bits.len();
 
         // Destination preparation.
 
@@ -570,7 +2244,8 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn copy_line_big_endian(
+    /// As with `VDI::copy_line_big_endian`.
+    pub fn copy_line_big_endian(
         &mut self,
         from: (u16, u16),
         src_width: usize,
@@ -579,6 +2254,11 @@ impl VDI for SDL2Vdi {
         width: usize,
         function: u8
     ) {
+        let (from, to, width) = match self.clip_copy_line(from, to, width) {
+            None => return,  // off surface; nothing to draw.
+            Some(clipped) => clipped,
+        };
+
         // First, expand the pen lookup table implied by `function`
         // into something we can index conveniently.
         // Index bit 1 maps to the source bit, while bit 0 maps to the destination bit.
@@ -592,10 +2272,15 @@ impl VDI for SDL2Vdi {
         let src_left = from.0 as usize;
         let src_width_u16 = (src_width + 15) / 16;
         let mut soffset = ((from.1 as usize) * src_width_u16) + (src_left / 16);
+        let largest_offset = from_bits.len();
+
+        if (src_left >= src_width) || (soffset >= largest_offset) {
+            return;  // nothing to read from the source; nothing to draw.
+        }
+
         let mut ix = src_left & 15;
         let mut src_word = from_bits[soffset] << ix;
         let src_width_adjusted = min(width, src_width - src_left);
-        let largest_offset = from_bits.len();
 
         // Destination preparation.
 
@@ -625,7 +2310,8 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn copy_rect(
+    /// As with `VDI::copy_rect`.
+    pub fn copy_rect(
         &mut self,
         from: (u16, u16),
         src_width: usize,
@@ -634,18 +2320,7 @@ impl VDI for SDL2Vdi {
         dimensions: (u16, u16),
         function: u8
     ) {
-        if to.0 >= self.dimensions.0 {
-            return;
-        }
-
-        if to.1 >= self.dimensions.1 {
-            return;
-        }
-
-        let adjusted_bottom = min(to.1 + dimensions.1, self.dimensions.1);
-        let adjusted_height = adjusted_bottom - to.1;
-
-        for y in 0..adjusted_height {
+        for y in self.clip_copy_rows(to, dimensions) {
             self.copy_line(
                 (from.0, from.1 + y), src_width, from_bits,
                 (to.0, to.1 + y), dimensions.0 as usize,
@@ -654,7 +2329,8 @@ impl VDI for SDL2Vdi {
         }
     }
 
-    fn copy_rect_big_endian(
+    /// As with `VDI::copy_rect_big_endian`.
+    pub fn copy_rect_big_endian(
         &mut self,
         from: (u16, u16),
         src_width: usize,
@@ -663,24 +2339,223 @@ impl VDI for SDL2Vdi {
         dimensions: (u16, u16),
         function: u8
     ) {
-        if to.0 >= self.dimensions.0 {
-            return;
+        for y in self.clip_copy_rows(to, dimensions) {
+            self.copy_line_big_endian(
+                (from.0, from.1 + y), src_width, from_bits,
+                (to.0, to.1 + y), dimensions.0 as usize,
+                function
+            );
+        }
+    }
+
+    /// As with `VDI::copy_rect_stippled`.
+    pub fn copy_rect_stippled(
+        &mut self,
+        from: (u16, u16),
+        src_width: usize,
+        from_bits: &[u16],
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        pen: u8
+    ) {
+        let p = if pen >= self.threshold { 255 } else { 0 };
+        let surface_width = self.dimensions.0 as usize;
+
+        for y in self.clip_copy_rows(to, dimensions) {
+            let (src, dst, width) = match self.clip_copy_rect_row(from, src_width, to, dimensions, y) {
+                None => continue,
+                Some(clipped) => clipped,
+            };
+
+            let mut offset = (dst.1 as usize) * surface_width + (dst.0 as usize);
+            for i in 0..width {
+                let x = dst.0 as usize + i;
+                let stipple = ((x ^ dst.1 as usize) & 1) == 0;
+                if stipple && bitmap_hit(from_bits, src_width, (src.0 + i as u16, src.1)) {
+                    self.backbuffer[offset] = p;
+                }
+                offset += 1;
+            }
+        }
+    }
+
+    /// As with `VDI::copy_rect_cursor`.
+    pub fn copy_rect_cursor(
+        &mut self,
+        ior_bits: &[u16],
+        xor_bits: &[u16],
+        src_width: usize,
+        from: (u16, u16),
+        to: (u16, u16),
+        dimensions: (u16, u16)
+    ) {
+        let surface_width = self.dimensions.0 as usize;
+
+        for y in self.clip_copy_rows(to, dimensions) {
+            let (src, dst, width) = match self.clip_copy_rect_row(from, src_width, to, dimensions, y) {
+                None => continue,
+                Some(clipped) => clipped,
+            };
+
+            let mut offset = (dst.1 as usize) * surface_width + (dst.0 as usize);
+            for i in 0..width {
+                let at = (src.0 + i as u16, src.1);
+                let mut p = self.backbuffer[offset];
+                if bitmap_hit(ior_bits, src_width, at) {
+                    p = 255;
+                }
+                if bitmap_hit(xor_bits, src_width, at) {
+                    p ^= 255;
+                }
+                self.backbuffer[offset] = p;
+                offset += 1;
+            }
+        }
+    }
+
+    /// Ends the batch, releasing the surface.
+    /// This is the same as dropping it.
+    pub fn end_batch(self) {
+    }
+
+    /// Translates a caller-supplied coordinate into surface coordinates.
+    /// The result may fall outside the surface in any direction.
+    fn translate(&self, at: (u16, u16)) -> (i32, i32) {
+        (at.0 as i32 + self.origin.0 as i32, at.1 as i32 + self.origin.1 as i32)
+    }
+
+    /// Draws a horizontal line as `hline` does.
+    /// If `phase` is given, the line's leftmost pixel, before clipping, takes that bit of
+    /// the pattern, instead of the pattern aligning with the surface.
+    fn hline_from_phase(&mut self, at: (u16, u16), to: u16, pattern: u16, phase: Option<u8>) {
+        let (mut left, y) = self.translate(at);
+        let mut right = self.translate((to, 0)).0;
+
+        if (y < 0) || (y >= self.dimensions.1 as i32) {
+            return; // off surface; nothing to draw.
+        }
+
+        if left >= right {
+            mem::swap(&mut left, &mut right);
         }
 
-        if to.1 >= self.dimensions.1 {
+        let unclipped_left = left;
+        let left = self.clip_x(left);
+        let right = self.clip_x(right);
+        let y = y as usize;
+        let width = self.dimensions.0 as usize;
+        let backbuf = &mut self.backbuffer;
+
+        let mut offset = y * width + left;
+
+        // Solid lines look the same at any phase, and can be filled wholesale.
+        if (pattern == 0xFFFF) || (pattern == 0x0000) {
+            let pen = if pattern != 0 { 255 } else { 0 };
+            for p in backbuf[offset..(offset + right - left)].iter_mut() {
+                *p = pen;
+            }
             return;
         }
 
-        let adjusted_bottom = min(to.1 + dimensions.1, self.dimensions.1);
-        let adjusted_height = adjusted_bottom - to.1;
+        let rotation = match phase {
+            None => left as i32,
+            Some(phase) => phase as i32 + left as i32 - unclipped_left,
+        };
+        let mut p = pattern.rotate_right((rotation & 15) as u32);
 
-        for y in 0..adjusted_height {
-            self.copy_line_big_endian(
-                (from.0, from.1 + y), src_width, from_bits,
-                (to.0, to.1 + y), dimensions.0 as usize,
-                function
-            );
+        for _ in left..right {
+            backbuf[offset] = if (p & 1) != 0 { 255 } else { 0 };
+            p = p.rotate_right(1);
+            offset += 1;
         }
     }
-}
 
+    /// Clamps a translated horizontal coordinate to `[0, width]`.
+    fn clip_x(&self, x: i32) -> usize {
+        max(0, min(x, self.dimensions.0 as i32)) as usize
+    }
+
+    /// Clamps a translated vertical coordinate to `[0, height]`.
+    fn clip_y(&self, y: i32) -> usize {
+        max(0, min(y, self.dimensions.1 as i32)) as usize
+    }
+
+    /// Works out which part of a `copy_line` request lands on the surface.
+    /// Answers the adjusted source coordinate, destination coordinate (now in
+    /// surface coordinates), and pixel count, or `None` if nothing is visible.
+    fn clip_copy_line(&self, from: (u16, u16), to: (u16, u16), width: usize) ->
+                Option<((u16, u16), (u16, u16), usize)> {
+        let (x, y) = self.translate(to);
+        let (surface_width, surface_height) = self.dimensions;
+
+        if (y < 0) || (y >= surface_height as i32) || (x >= surface_width as i32) {
+            return None;
+        }
+
+        // Pixels falling off the left edge consume source pixels too.
+        let skip = if x < 0 { (-x) as usize } else { 0 };
+        if skip >= width {
+            return None;
+        }
+
+        let src_left = from.0 as usize + skip;
+        if src_left > u16::max_value() as usize {
+            return None;
+        }
+
+        Some(((src_left as u16, from.1), ((x + skip as i32) as u16, y as u16), width - skip))
+    }
+
+    /// Answers the range of rows of a `copy_rect` request, relative to `to`,
+    /// which land on the surface vertically.
+    fn clip_copy_rows(&self, to: (u16, u16), dimensions: (u16, u16)) -> ops::Range<u16> {
+        let (x, y) = self.translate(to);
+        let (surface_width, surface_height) = self.dimensions;
+
+        if (x >= surface_width as i32) || (y >= surface_height as i32) {
+            return 0..0;
+        }
+
+        let first = max(0, -y);
+        let last = min(dimensions.1 as i32, surface_height as i32 - y);
+        if first >= last {
+            return 0..0;
+        }
+
+        (first as u16)..(last as u16)
+    }
+
+    /// Works out which part of row `y` of a `copy_rect` request, relative to `to`,
+    /// lands on the surface and reads from within the source bitmap.
+    /// Answers as `clip_copy_line` does, except that the pixel count also stops
+    /// at the right edge of the surface and at `src_width`,
+    /// so every source coordinate in the row fits in a `u16`.
+    fn clip_copy_rect_row(
+        &self,
+        from: (u16, u16),
+        src_width: usize,
+        to: (u16, u16),
+        dimensions: (u16, u16),
+        y: u16
+    ) -> Option<((u16, u16), (u16, u16), usize)> {
+        let (src_top, dst_top) = (from.1 as usize + y as usize, to.1 as usize + y as usize);
+        if (src_top > u16::max_value() as usize) || (dst_top > u16::max_value() as usize) {
+            return None;
+        }
+
+        let (src, dst, width) =
+            match self.clip_copy_line((from.0, src_top as u16), (to.0, dst_top as u16), dimensions.0 as usize) {
+                None => return None,
+                Some(clipped) => clipped,
+            };
+
+        let width = min(width, self.dimensions.0 as usize - dst.0 as usize);
+        let width = min(width, src_width.saturating_sub(src.0 as usize));
+        let width = min(width, u16::max_value() as usize + 1 - src.0 as usize);
+        if width == 0 {
+            return None;
+        }
+
+        Some((src, dst, width))
+    }
+}
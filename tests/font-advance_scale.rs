@@ -0,0 +1,75 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// One solid glyph, 6 pixels wide and 2 tall.
+static BITS : [u16; 2] = [0xFC00, 0xFC00];
+static EDGES : [u16; 2] = [0, 6];
+
+static SIX_WIDE : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       2,
+    height:         2,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+
+fn context<'a>(vdi: &'a mut vdi::VDI, advance_scale: Option<u32>) -> font::TextContext<'a> {
+    let mut t = font::TextContext::new(vdi, &SIX_WIDE);
+    t.left = 10;
+    t.baseline = 2;
+    t.advance_scale = advance_scale;
+    t
+}
+
+#[test]
+fn fractional_advances() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 800, 4, "blah").unwrap();
+
+    // 6 * 1.05 = 6.3 pixels per glyph; whole-pixel advances would drift by 30 pixels.
+    let scale = 68813;
+    let mut t = context(vdi, Some(scale));
+    for n in 1..101 {
+        t.simple_put_char(0);
+        let exact = 10.0 + n as f64 * 6.0 * scale as f64 / 65536.0;
+        let error = (t.left as f64 - exact).abs();
+        assert!(error <= 0.5, "After {} glyphs, left is {} but should be {}", n, t.left, exact);
+    }
+    assert_eq!(t.left, 640);
+}
+
+#[test]
+fn whole_advances_unchanged() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 800, 4, "blah").unwrap();
+
+    {
+        let mut t = context(vdi, None);
+        for _ in 0..100 {
+            t.simple_put_char(0);
+        }
+        assert_eq!(t.left, 610);
+        assert_eq!(t.left_fraction, 0);
+    }
+    let unscaled = vdi.snapshot();
+
+    vdi.rect((0, 0), (800, 4), &[0; 16]);
+    {
+        let mut t = context(vdi, Some(0x10000));
+        for _ in 0..100 {
+            t.simple_put_char(0);
+        }
+        assert_eq!(t.left, 610);
+        assert_eq!(t.left_fraction, 0);
+    }
+    vdi::assert_snapshots_eq_with_width(&unscaled, &vdi.snapshot(), 800);
+}
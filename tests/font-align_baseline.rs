@@ -0,0 +1,75 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// One solid glyph, 8 pixels wide.
+static BITS : [u16; 6] = [0xFF00; 6];
+static EDGES : [u16; 2] = [0, 8];
+
+
+fn solid(ascender: u16) -> font::Font<'static> {
+    font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       ascender,
+        height:         ascender,
+        fixed_pitch:    None,
+        notdef:         0,
+    }
+}
+
+#[test]
+fn align_baseline_to() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let short = solid(2);
+    let tall = solid(6);
+
+    let mut t = font::TextContext::new(vdi, &short);
+    t.baseline = 2;
+
+    t.align_baseline_to(&tall);
+    assert_eq!(t.baseline, 6);
+    assert_eq!(t.left, 0);
+
+    // Aligning to a shorter font leaves the baseline alone.
+    t.align_baseline_to(&short);
+    assert_eq!(t.baseline, 6);
+
+    t.simple_put_char(0);
+    t.font = &tall;
+    t.simple_put_char(0);
+
+    // The glyph tops differ, but both end on the row above the baseline.
+    for y in 0..8 {
+        let short_inked = (y >= 4) && (y < 6);
+        let tall_inked = y < 6;
+        assert_eq!(t.vdi.get_point((0, y)), if short_inked { 255 } else { 0 }, "Short, row {}", y);
+        assert_eq!(t.vdi.get_point((8, y)), if tall_inked { 255 } else { 0 }, "Tall, row {}", y);
+    }
+}
+
+#[test]
+fn fit_baseline_to() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let short = solid(2);
+    let tall = solid(6);
+
+    // A line starting on row 4; aligning again to the same font changes nothing.
+    let mut t = font::TextContext::new(vdi, &short);
+    t.baseline = 6;
+
+    t.fit_baseline_to(4, &tall);
+    assert_eq!(t.baseline, 10);
+    t.fit_baseline_to(4, &tall);
+    assert_eq!(t.baseline, 10);
+    t.fit_baseline_to(4, &short);
+    assert_eq!(t.baseline, 10);
+}
@@ -0,0 +1,62 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+fn context<'a>(vdi: &'a mut vdi::VDI, advance_scale: Option<u32>) -> font::TextContext<'a> {
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = 16;
+    t.strike_fn = 0b0101;
+    t.advance_scale = advance_scale;
+    t
+}
+
+#[test]
+fn char_index_at() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 16, "blah").unwrap();
+    let before = vdi.snapshot();
+    let t = context(vdi, None);
+
+    // The system font is 8 pixels wide; a click 20 pixels in lands in the third character.
+    assert_eq!(t.char_index_at("hello", 20), 2);
+    assert_eq!(t.char_index_at("hello", 0), 0);
+    assert_eq!(t.char_index_at("hello", 7), 0);
+    assert_eq!(t.char_index_at("hello", 8), 1);
+    assert_eq!(t.char_index_at("hello", 39), 4);
+    assert_eq!(t.char_index_at("hello", 40), 5);
+    assert_eq!(t.char_index_at("", 3), 0);
+
+    assert_eq!(t.left, 16);
+    vdi::assert_snapshots_eq_with_width(&t.vdi.snapshot(), &before, 128);
+}
+
+#[test]
+fn char_index_at_matches_layout() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 16, "blah").unwrap();
+
+    // Advances of 8.5 pixels round to uneven cells.
+    let mut t = context(vdi, Some(0x11000));
+    let s = "abcdef";
+
+    let mut lefts = Vec::new();
+    for chr in s.bytes() {
+        lefts.push(t.left);
+        t.simple_put_char(chr);
+    }
+    lefts.push(t.left);
+
+    t.left = 16;
+    t.left_fraction = 0;
+    for i in 0..s.len() {
+        let (cell_left, cell_right) = (lefts[i] - 16, lefts[i + 1] - 16);
+        assert_eq!(t.char_index_at(s, cell_left), i, "Left of cell {}", i);
+        assert_eq!(t.char_index_at(s, cell_right - 1), i, "Right of cell {}", i);
+    }
+    assert_eq!(t.char_index_at(s, lefts[s.len()] - 16), s.len());
+}
@@ -0,0 +1,73 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// One 8-pixel wide glyph, 8 rows tall: 5 rows above the baseline, 3 below.
+// Each row is a different width, so rows can be told apart.
+static BITS : [u16; 8] = [
+    0x8000, 0xC000, 0xE000, 0xF000, 0xF800, 0xFC00, 0xFE00, 0xFF00,
+];
+static EDGES : [u16; 2] = [0, 8];
+
+static DESCENDING : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       5,
+    height:         8,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+
+fn put_char(vdi: &mut vdi::VDI, top_margin: u16, bottom_margin: u16) {
+    let mut t = font::TextContext::new(vdi, &DESCENDING);
+    t.baseline = 10;
+    t.top_margin = top_margin;
+    t.bottom_margin = bottom_margin;
+    t.simple_put_char(0);
+}
+
+// Answers how many pixels are set at the left of row y.
+fn row_width(vdi: &vdi::VDI, y: u16) -> u16 {
+    (0..16).take_while(|&x| vdi.get_point((x, y)) == 255).count() as u16
+}
+
+#[test]
+fn descender_clipped_at_bottom_margin() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 24, "blah").unwrap();
+    vdi.rect((0, 0), (16, 24), &vdi::GRAY50);
+
+    // The glyph spans rows 5 through 12; the margin cuts off its last descender row.
+    put_char(vdi, 0, 12);
+
+    for y in 5..12 {
+        assert_eq!(row_width(vdi, y), y - 4, "Row {}", y);
+    }
+    for y in 12..24 {
+        assert_eq!(vdi.get_point((0, y)), if y & 1 == 0 { 0 } else { 255 }, "Row {}", y);
+    }
+}
+
+#[test]
+fn ascender_clipped_at_top_margin() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 24, "blah").unwrap();
+
+    // The margin cuts off the first two ascender rows; the descender is whole.
+    put_char(vdi, 7, 24);
+
+    for y in 0..7 {
+        assert_eq!(row_width(vdi, y), 0, "Row {}", y);
+    }
+    for y in 7..13 {
+        assert_eq!(row_width(vdi, y), y - 4, "Row {}", y);
+    }
+    assert_eq!(row_width(vdi, 13), 0);
+}
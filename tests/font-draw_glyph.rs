@@ -0,0 +1,52 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+static PAPER : [u16; 16] = [0xFFFF; 16];
+
+
+#[test]
+fn draw_glyph() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 16, "blah").unwrap();
+    let f = font::borrow_system_font();
+
+    vdi.rect((0, 0), (64, 16), &PAPER);
+
+    // 0b1010 copies the glyph verbatim, overwriting its whole cell.
+    let a_advance = f.draw_glyph(vdi, b'A', (4, 4), 0b1010);
+    assert_eq!(a_advance, 8);
+
+    let mut a_cell = Vec::new();
+    for y in 0..16 {
+        for x in 0..64 {
+            a_cell.push(vdi.get_point((x, y)));
+        }
+    }
+
+    let b_advance = f.draw_glyph(vdi, b'B', (4 + a_advance, 4), 0b1010);
+    assert_eq!(b_advance, 8);
+
+    // Drawing B must not disturb anything left of where A's advance ended.
+    for y in 0..16 {
+        for x in 0..(4 + a_advance) {
+            assert_eq!(
+                vdi.get_point((x, y)), a_cell[(y * 64 + x) as usize],
+                "Point ({}, {}) changed", x, y
+            );
+        }
+    }
+
+    // ...yet B really was drawn to the right of it.
+    let mut b_ink = false;
+    for y in 4..12 {
+        for x in (4 + a_advance)..(4 + a_advance + b_advance) {
+            b_ink = b_ink || (vdi.get_point((x, y)) != 255);
+        }
+    }
+    assert!(b_ink);
+}
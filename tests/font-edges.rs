@@ -0,0 +1,34 @@
+extern crate gemini;
+
+
+use gemini::font;
+
+
+// Three proportional glyphs, two pixels tall, in a 20-pixel strip:
+// columns 0-2, 5-6, and 9-16 hold ink; everything else is blank.
+static STRIP : [u16; 4] = [
+    0b1110011001111111, 0b1000000000000000,
+    0b1010001001000001, 0b1000000000000000,
+];
+
+
+#[test]
+fn edges_from_fixed() {
+    assert_eq!(font::edges_from_fixed(4, 8), vec![0, 8, 16, 24, 32]);
+    assert_eq!(font::edges_from_fixed(0, 8), vec![0]);
+
+    let edges = font::edges_from_fixed(256, 8);
+    assert_eq!(edges.len(), 257);
+    assert_eq!(&edges[..], &font::SYSTEM_FONT.left_edges[..]);
+}
+
+#[test]
+fn edges_from_gaps() {
+    assert_eq!(font::edges_from_gaps(&STRIP, 20, 2, 0), vec![0, 5, 9, 20]);
+}
+
+#[test]
+fn edges_from_gaps_inverted() {
+    let inverted : Vec<u16> = STRIP.iter().map(|w| !w).collect();
+    assert_eq!(font::edges_from_gaps(&inverted, 20, 2, 1), vec![0, 5, 9, 20]);
+}
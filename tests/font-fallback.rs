@@ -0,0 +1,123 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// One solid glyph, 4 pixels wide and 2 tall, sitting on the baseline.
+static BITS : [u16; 2] = [0xF000; 2];
+static EDGES : [u16; 2] = [0, 4];
+
+static BLOCK_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       2,
+    height:         2,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+// Glyphs up to 'A', each 4 pixels wide and 6 tall, all ascender;
+// only 'A' is inked, as a solid block.  Its strip is 264 pixels, or 17 words, wide.
+fn tall_bits() -> Vec<u16> {
+    let mut bits = vec![0u16; 17 * 6];
+    for row in 0..6 {
+        bits[row * 17 + 16] = 0x0F00;
+    }
+    bits
+}
+
+
+fn context<'a>(vdi: &'a mut vdi::VDI, f: &'a font::Font<'a>, fallbacks: Vec<&'a font::Font<'a>>) -> font::TextContext<'a> {
+    let mut t = font::TextContext::new(vdi, f);
+    t.fallbacks = fallbacks;
+    t.left = 2;
+    t.baseline = 12;
+    t
+}
+
+#[test]
+fn fallback() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let system = font::borrow_system_font();
+
+    // The block font has only glyph 0; 'A' comes from the system font.
+    let mut t = context(vdi, &BLOCK_FONT, vec![system]);
+    assert_eq!(t.missing_glyphs("\x00A"), Vec::<usize>::new());
+    assert_eq!(t.get_real_size(b'A'), (8, 8, 7));
+    assert_eq!(t.get_real_size(0), (4, 2, 2));
+    assert_eq!(t.char_index_at("\x00A", 11), 1);
+
+    t.simple_put_char(0);
+    t.simple_put_char(b'A');
+    assert_eq!(t.left, 14);
+    assert_eq!(t.font.ascender, 2);
+
+    // Both glyphs sit on the one baseline: the block fills the two rows above it,
+    // while 'A' rises no higher than the system font's ascender above it.
+    let top = 12 - system.ascender;
+    for y in 0..16 {
+        for x in 2..6 {
+            assert_eq!(t.vdi.get_point((x, y)), if (y >= 10) && (y < 12) { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+        if y < top {
+            assert!((6..14).all(|x| t.vdi.get_point((x, y)) == 0), "Row {}", y);
+        }
+    }
+    assert!((6..14).any(|x| t.vdi.get_point((x, top + 1)) == 255));
+}
+
+#[test]
+fn fallback_chain() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let system = font::borrow_system_font();
+    let bits = tall_bits();
+    let edges = font::edges_from_fixed(66, 4);
+    let tall = font::Font {
+        bits:           &bits,
+        left_edges:     &edges,
+        width:          264,
+        ascender:       6,
+        height:         6,
+        fixed_pitch:    None,
+        notdef:         0,
+    };
+
+    // 'A' comes from the tall font, the first fallback to have it;
+    // only the system font, second in line, has 'B'.
+    let mut t = context(vdi, &BLOCK_FONT, vec![&tall, system]);
+    assert_eq!(t.missing_glyphs("\x00AB"), Vec::<usize>::new());
+    assert_eq!(t.get_real_size(b'A'), (4, 6, 6));
+    assert_eq!(t.get_real_size(b'B'), (8, 8, 7));
+
+    for chr in b"\x00AB".iter() {
+        t.simple_put_char(*chr);
+    }
+    assert_eq!(t.left, 18);
+
+    // The tall block's top row sits exactly its ascender above the baseline.
+    for y in 0..16 {
+        for x in 6..10 {
+            let inked = (y >= 12 - tall.ascender) && (y < 12);
+            assert_eq!(t.vdi.get_point((x, y)), if inked { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn fallback_notdef() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    // Without a fallback, the block font's notdef glyph stands in for 'A'.
+    let t = context(vdi, &BLOCK_FONT, vec![]);
+    assert_eq!(t.missing_glyphs("\x00A"), vec![1]);
+    assert_eq!(t.get_real_size(b'A'), (4, 2, 2));
+}
@@ -0,0 +1,66 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Two solid glyphs, two pixels tall: glyph 0 is 2 pixels wide, glyph 1 is 6.
+static BITS : [u16; 2] = [0xFF00, 0xFF00];
+static EDGES : [u16; 3] = [0, 2, 8];
+
+static PAPER : [u16; 16] = [0xFFFF; 16];
+
+
+fn narrow_and_wide(fixed_pitch: Option<u16>) -> font::Font<'static> {
+    font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       2,
+        height:         2,
+        fixed_pitch:    fixed_pitch,
+        notdef:         0,
+    }
+}
+
+#[test]
+fn proportional() {
+    let f = narrow_and_wide(None);
+    assert_eq!(f.advance(0), 2);
+    assert_eq!(f.advance(1), 6);
+}
+
+#[test]
+fn fixed_pitch() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 8, "blah").unwrap();
+    let f = narrow_and_wide(Some(8));
+
+    assert_eq!(f.glyph_width(0), 2);
+    assert_eq!(f.glyph_width(1), 6);
+
+    vdi.rect((0, 0), (32, 8), &PAPER);
+
+    // 0b0101 draws set bits in black.
+    let first = f.draw_glyph(vdi, 0, (0, 0), 0b0101);
+    let second = f.draw_glyph(vdi, 1, (first, 0), 0b0101);
+    assert_eq!(first, 8);
+    assert_eq!(second, 8);
+
+    // Each glyph sits centered in its own 8-pixel cell.
+    for x in 0..16 {
+        let inked = ((x >= 3) && (x < 5)) || ((x >= 9) && (x < 15));
+        assert_eq!(vdi.get_point((x, 0)), if inked { 0 } else { 255 }, "Column {}", x);
+    }
+
+    // TextContext advances by the same pitch.
+    let mut t = font::TextContext::new(vdi, &f);
+    t.baseline = 4;
+    t.strike_fn = 0b0101;
+    t.simple_put_char(0);
+    assert_eq!(t.left, 8);
+    t.simple_put_char(1);
+    assert_eq!(t.left, 16);
+}
@@ -0,0 +1,74 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Draws into a fresh surface, answering its snapshot and where the pen ended up.
+// The system font is 8 pixels wide, so ten characters fit between the margins.
+fn render<F>(advance_scale: Option<u32>, draw: F) -> (Vec<u8>, (u16, u16))
+    where F: Fn(&mut font::TextContext) {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = 8;
+    t.left_margin = 8;
+    t.right_margin = 88;
+    t.advance_scale = advance_scale;
+    draw(&mut t);
+
+    (t.vdi.snapshot(), (t.left, t.baseline))
+}
+
+// Draws each of `lines` from the left margin, one line height below the last.
+fn by_hand(advance_scale: Option<u32>, lines: &[&str]) -> (Vec<u8>, (u16, u16)) {
+    render(advance_scale, |t| {
+        for (n, line) in lines.iter().enumerate() {
+            if n > 0 {
+                t.left = 8;
+                t.left_fraction = 0;
+                t.baseline += 8;
+            }
+            for chr in line.bytes() {
+                t.simple_put_char(chr);
+            }
+        }
+    })
+}
+
+#[test]
+fn flow_text() {
+    let s = "hello world again";
+    let (flowed, pen) = render(None, |t| {
+        assert_eq!(t.wrap_positions(s), vec![6, 12]);
+        t.flow_text(s);
+    });
+
+    let (expected, expected_pen) = by_hand(None, &["hello", "world", "again"]);
+    vdi::assert_snapshots_eq_with_width(&flowed, &expected, 128);
+    assert_eq!(pen, expected_pen);
+    assert_eq!(pen, (48, 23));
+}
+
+#[test]
+fn flow_text_advance_scale() {
+    // At 8.5 pixels a character, "worl" wraps, just as wrap_positions says.
+    let s = "hello worl";
+    let (flowed, pen) = render(Some(0x11000), |t| {
+        assert_eq!(t.wrap_positions(s), vec![6]);
+        t.flow_text(s);
+    });
+
+    let (expected, expected_pen) = by_hand(Some(0x11000), &["hello", "worl"]);
+    vdi::assert_snapshots_eq_with_width(&flowed, &expected, 128);
+    assert_eq!(pen, expected_pen);
+}
+
+#[test]
+fn flow_text_newline() {
+    let (_, pen) = render(None, |t| t.flow_text("hi\n"));
+    assert_eq!(pen, (8, 15));
+}
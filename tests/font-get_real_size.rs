@@ -0,0 +1,52 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Two glyphs, four pixels tall: a 3-wide bar and a 5-wide box.
+static BITS : [u16; 4] = [0xFF00, 0xE800, 0xE800, 0xFF00];
+static EDGES : [u16; 3] = [0, 3, 8];
+
+static TINY_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       3,
+    height:         4,
+    fixed_pitch:    None,
+    notdef:         1,
+};
+
+static NO_NOTDEF_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       3,
+    height:         4,
+    fixed_pitch:    None,
+    notdef:         2,
+};
+
+
+fn real_size(vdi: &mut vdi::VDI, f: &font::Font, chr: u8) -> (u16, u16, u16) {
+    let t = font::TextContext::new(vdi, f);
+    t.get_real_size(chr)
+}
+
+#[test]
+fn get_real_size_255() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    // The system font covers byte 255 itself.
+    assert_eq!(real_size(vdi, font::borrow_system_font(), 255), (8, 8, 7));
+
+    // Smaller fonts measure it as their notdef glyph...
+    assert_eq!(real_size(vdi, &TINY_FONT, 255), (5, 4, 3));
+
+    // ...or as nothing at all, if they lack that too.
+    assert_eq!(real_size(vdi, &NO_NOTDEF_FONT, 255), (0, 4, 3));
+}
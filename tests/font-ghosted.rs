@@ -0,0 +1,106 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// A single solid 8x8 glyph.
+static BITS : [u16; 8] = [0xFF00; 8];
+static EDGES : [u16; 2] = [0, 8];
+
+static SOLID_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       8,
+    height:         8,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+
+#[test]
+fn ghosted() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    let mut t = font::TextContext::new(vdi, &SOLID_FONT);
+    t.left = 8;
+    t.baseline = 16;
+    t.ghosted = true;
+    t.simple_put_char(0);
+
+    let mut set = 0;
+    for y in 0..32 {
+        for x in 0..32 {
+            let p = t.vdi.get_point((x, y));
+            let in_cell = (x >= 8) && (x < 16) && (y >= 8) && (y < 16);
+            if in_cell && ((x + y) & 1 == 1) {
+                assert_eq!(p, 255, "Point ({}, {})", x, y);
+                set += 1;
+            }
+            else {
+                assert_eq!(p, 0, "Point ({}, {})", x, y);
+            }
+        }
+    }
+    assert_eq!(set, 32);
+}
+
+#[test]
+fn ghosted_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+
+    // Ghosting must not reach beyond the margins, even within the glyph's cell.
+    let mut t = font::TextContext::new(vdi, &SOLID_FONT);
+    t.left = 8;
+    t.baseline = 16;
+    t.ghosted = true;
+    t.right_margin = 12;
+    t.simple_put_char(0);
+
+    for y in 8..16 {
+        for x in 12..16 {
+            assert_eq!(t.vdi.get_point((x, y)), 255, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn ghosted_black_on_white() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+
+    // Black ink is thinned to every other pixel; the paper between stays white.
+    let mut t = font::TextContext::new(vdi, &SOLID_FONT);
+    t.left = 8;
+    t.baseline = 16;
+    t.strike_fn = vdi::raster_op_bits(vdi::RasterOp::NotSrc);
+    t.ghosted = true;
+    t.simple_put_char(0);
+
+    let mut set = 0;
+    for y in 0..32 {
+        for x in 0..32 {
+            let p = t.vdi.get_point((x, y));
+            let in_cell = (x >= 8) && (x < 16) && (y >= 8) && (y < 16);
+            if in_cell && ((x + y) & 1 == 1) {
+                assert_eq!(p, 0, "Point ({}, {})", x, y);
+            }
+            else {
+                assert_eq!(p, 255, "Point ({}, {})", x, y);
+                set += 1;
+            }
+        }
+    }
+    assert_eq!(set, 32 * 32 - 32);
+}
@@ -0,0 +1,175 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Three glyphs, four pixels tall: a 3-wide bar, a 5-wide box, and a 2-wide bar.
+static BITS : [u16; 4] = [0xFFF8, 0xE8B8, 0xE8B8, 0xFFF8];
+static EDGES : [u16; 4] = [0, 3, 8, 10];
+
+static TINY_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       4,
+    height:         4,
+    fixed_pitch:    None,
+    notdef:         1,
+};
+
+
+// One 4-pixel wide glyph, striped when read as a 16-pixel strip four rows tall,
+// but solid when the same table is read as a 32-pixel strip two rows tall.
+static STRIPES : [u16; 4] = [0xF000, 0x0000, 0xF000, 0x0000];
+static STRIPE_EDGES : [u16; 2] = [0, 4];
+
+static STRIPED_FONT : font::Font<'static> = font::Font {
+    bits:           &STRIPES,
+    left_edges:     &STRIPE_EDGES,
+    width:          16,
+    ascender:       4,
+    height:         4,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+static SOLID_FONT : font::Font<'static> = font::Font {
+    bits:           &STRIPES,
+    left_edges:     &STRIPE_EDGES,
+    width:          32,
+    ascender:       2,
+    height:         2,
+    fixed_pitch:    None,
+    notdef:         0,
+};
+
+
+// Draws the same text into a fresh surface, with or without a glyph cache,
+// answering the resulting snapshot and how many glyphs ended up cached.
+fn render(cached: bool, f: &font::Font) -> (Vec<u8>, usize) {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 160, 32, "blah").unwrap();
+    vdi.rect((0, 0), (160, 32), &vdi::GRAY50);
+
+    let mut t = font::TextContext::new(vdi, f);
+    t.left = 3;
+    t.baseline = 10;
+    t.strike_fn = 0b0101;
+    t.glyph_cache = if cached { Some(font::GlyphCache::new()) } else { None };
+    t.left_margin = 5;
+    t.right_margin = 150;
+    t.top_margin = 6;
+    t.bottom_margin = 24;
+
+    // Draw the text twice, so the second pass comes from the cache;
+    // the margins clip the first glyph, and the top of the first line.
+    for _ in 0..2 {
+        for chr in b"Hello, World! {|}~".iter() {
+            t.simple_put_char(*chr);
+        }
+        t.left = 3;
+        t.baseline += 8;
+    }
+
+    let cached_glyphs = t.glyph_cache.as_ref().map(|c| c.len()).unwrap_or(0);
+    (t.vdi.snapshot(), cached_glyphs)
+}
+
+#[test]
+fn glyph_cache_matches_font() {
+    let (uncached, _) = render(false, font::borrow_system_font());
+    let (cached, count) = render(true, font::borrow_system_font());
+    vdi::assert_snapshots_eq_with_width(&uncached, &cached, 160);
+    assert_eq!(count, 14);
+
+    // Missing glyphs come from the notdef glyph, which is cached once.
+    let (uncached, _) = render(false, &TINY_FONT);
+    let (cached, count) = render(true, &TINY_FONT);
+    vdi::assert_snapshots_eq_with_width(&uncached, &cached, 160);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn glyph_cache_follows_font() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 16, "blah").unwrap();
+
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.baseline = 8;
+    t.glyph_cache = Some(font::GlyphCache::new());
+    t.right_margin = 64;
+    t.bottom_margin = 16;
+
+    t.simple_put_char(b'A');
+    t.simple_put_char(b'B');
+    assert_eq!(t.glyph_cache.as_ref().unwrap().len(), 2);
+
+    // Switching fonts keeps the first font's glyphs apart from the new font's.
+    t.font = &TINY_FONT;
+    t.simple_put_char(0);
+    assert_eq!(t.glyph_cache.as_ref().unwrap().len(), 3);
+
+    // The tiny font's first glyph is a solid 3x4 bar.
+    for y in 4..8 {
+        for x in 16..19 {
+            assert_eq!(t.vdi.get_point((x, y)), 255, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn glyph_cache_follows_layout() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    let mut t = font::TextContext::new(vdi, &STRIPED_FONT);
+    t.baseline = 8;
+    t.glyph_cache = Some(font::GlyphCache::new());
+    t.simple_put_char(0);
+
+    // A font sharing the striped font's tables, but not their layout, has glyphs of its own.
+    t.font = &SOLID_FONT;
+    t.simple_put_char(0);
+    assert_eq!(t.glyph_cache.as_ref().unwrap().len(), 2);
+
+    for y in 4..8 {
+        for x in 0..8 {
+            let set = if x < 4 { y & 1 == 0 } else { y >= 6 };
+            assert_eq!(t.vdi.get_point((x, y)), if set { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn glyph_cache_mixed_fonts() {
+    // The tiny font has glyphs 0 to 2 only; everything else comes from the system font.
+    let draw = |vdi: &mut vdi::VDI, cached: bool| -> usize {
+        let mut t = font::TextContext::new(vdi, &TINY_FONT);
+        t.fallbacks = vec![font::borrow_system_font()];
+        t.baseline = 8;
+        t.glyph_cache = if cached { Some(font::GlyphCache::new()) } else { None };
+        for _ in 0..2 {
+            t.left = 0;
+            for chr in b"\x00a\x01b\x00a".iter() {
+                t.simple_put_char(*chr);
+            }
+        }
+        t.glyph_cache.as_ref().map(|c| c.len()).unwrap_or(0)
+    };
+
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 16, "blah").unwrap();
+    draw(vdi, false);
+    let uncached = vdi.snapshot();
+
+    vdi.rect((0, 0), (64, 16), &[0; 16]);
+    // All four glyphs stay cached, however often the fonts alternate.
+    assert_eq!(draw(vdi, true), 4);
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &uncached, 64);
+}
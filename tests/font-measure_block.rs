@@ -0,0 +1,54 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+fn measure(s: &str) -> (u16, u16) {
+    measure_scaled(None, s)
+}
+
+fn measure_scaled(advance_scale: Option<u32>, s: &str) -> (u16, u16) {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+    let before = vdi.snapshot();
+
+    // The system font is 8 pixels square, so ten characters fit between the margins.
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = 8;
+    t.left_margin = 8;
+    t.right_margin = 88;
+    t.advance_scale = advance_scale;
+
+    let extent = t.measure_block(s);
+    assert_eq!(t.left, 8);
+    vdi::assert_snapshots_eq_with_width(&t.vdi.snapshot(), &before, 128);
+    extent
+}
+
+#[test]
+fn measure_block() {
+    // "wonderful" moves to a line of its own, and is the wider of the two.
+    assert_eq!(measure("hello wonderful"), (72, 16));
+    assert_eq!(measure("hello"), (40, 8));
+    assert_eq!(measure(""), (0, 0));
+}
+
+#[test]
+fn measure_block_newlines() {
+    // Neither the space left at the end of a wrapped line nor a final newline counts.
+    assert_eq!(measure("abcdef ghij"), (48, 16));
+    assert_eq!(measure("ab\ncdef\n"), (32, 16));
+    assert_eq!(measure("ab\n\ncd"), (16, 24));
+}
+
+#[test]
+fn measure_block_advance_scale() {
+    // At 8.5 pixels a character, "worl" no longer fits beside "hello",
+    // and the nine characters of "wonderful" span 76.5 pixels, rounded up.
+    assert_eq!(measure("hello worl"), (80, 8));
+    assert_eq!(measure_scaled(Some(0x11000), "hello worl"), (43, 16));
+    assert_eq!(measure_scaled(Some(0x11000), "hello wonderful"), (77, 16));
+}
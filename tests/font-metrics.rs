@@ -0,0 +1,48 @@
+extern crate gemini;
+
+
+use gemini::font;
+
+
+// Three glyphs, four pixels tall: 3, 5, and 2 pixels wide.
+static BITS : [u16; 4] = [0xFFF8, 0xE8B8, 0xE8B8, 0xFFF8];
+static EDGES : [u16; 4] = [0, 3, 8, 10];
+
+
+#[test]
+fn system_font_metrics() {
+    let f = font::borrow_system_font();
+    let m = f.metrics();
+
+    assert_eq!(m.ascender, f.ascender);
+    assert_eq!(m.height, f.height);
+    assert_eq!(m.ascender + m.descender, m.height);
+    assert_eq!(m.line_height, m.height);
+
+    let widest = (0..255u8).map(|chr| f.glyph_width(chr)).max().unwrap();
+    assert_eq!(m.max_advance, widest);
+}
+
+#[test]
+fn metrics() {
+    let mut f = font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       3,
+        height:         4,
+        fixed_pitch:    None,
+        notdef:         0,
+    };
+
+    assert_eq!(f.metrics(), font::FontMetrics {
+        ascender:       3,
+        descender:      1,
+        height:         4,
+        max_advance:    5,
+        line_height:    4,
+    });
+
+    f.fixed_pitch = Some(8);
+    assert_eq!(f.metrics().max_advance, 8);
+}
@@ -0,0 +1,50 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Glyphs for bytes 0 through 'a' only.
+static BITS : [u16; 1] = [0; 1];
+static EDGES : [u16; 99] = [0; 99];
+
+
+#[test]
+fn missing_glyphs() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let before = vdi.snapshot();
+
+    let f = font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       1,
+        height:         1,
+        fixed_pitch:    None,
+        notdef:         0,
+    };
+    let mut t = font::TextContext::new(vdi, &f);
+    t.left = 3;
+    t.baseline = 5;
+
+    assert_eq!(t.missing_glyphs("abc"), vec![1, 2]);
+    assert_eq!(t.missing_glyphs("a\u{e9}a"), vec![1, 2]);
+    assert_eq!(t.missing_glyphs("aaa"), Vec::<usize>::new());
+    assert_eq!(t.left, 3);
+    assert_eq!(t.baseline, 5);
+
+    vdi::assert_snapshots_eq_with_width(&t.vdi.snapshot(), &before, 32);
+}
+
+#[test]
+fn system_font_covers_every_byte() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    let t = font::TextContext::new(vdi, font::borrow_system_font());
+
+    assert_eq!(t.missing_glyphs("Gemini \u{ff}"), Vec::<usize>::new());
+}
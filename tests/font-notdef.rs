@@ -0,0 +1,61 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// Three glyphs, four pixels tall: a 3-wide bar, a 5-wide box, and a 2-wide bar.
+static BITS : [u16; 4] = [0xFFF8, 0xE8B8, 0xE8B8, 0xFFF8];
+static EDGES : [u16; 4] = [0, 3, 8, 10];
+
+static TINY_FONT : font::Font<'static> = font::Font {
+    bits:           &BITS,
+    left_edges:     &EDGES,
+    width:          16,
+    ascender:       4,
+    height:         4,
+    fixed_pitch:    None,
+    notdef:         1,
+};
+
+
+fn put_char(vdi: &mut vdi::VDI, f: &font::Font, chr: u8) -> u16 {
+    vdi.rect((0, 0), (32, 16), &[0xFFFF; 16]);
+
+    let mut t = font::TextContext::new(vdi, f);
+    t.left = 4;
+    t.baseline = 8;
+    t.strike_fn = 0b0101;
+    t.simple_put_char(chr);
+    t.left - 4
+}
+
+#[test]
+fn notdef() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    assert_eq!(TINY_FONT.glyph_index(2), Some(2));
+    assert_eq!(TINY_FONT.glyph_index(3), Some(1));
+    assert_eq!(TINY_FONT.glyph_index(255), Some(1));
+
+    assert_eq!(put_char(vdi, &TINY_FONT, 1), 5);
+    let box_glyph = vdi.snapshot();
+
+    assert_eq!(put_char(vdi, &TINY_FONT, 255), 5);
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &box_glyph, 32);
+
+    assert_eq!(TINY_FONT.draw_glyph(vdi, 200, (20, 0), 0b0101), 5);
+}
+
+#[test]
+fn byte_255() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    // The system font covers every byte, including the last.
+    assert_eq!(put_char(vdi, font::borrow_system_font(), 255), 8);
+}
@@ -0,0 +1,90 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+// One solid glyph, 4x4 pixels.
+static BITS : [u16; 4] = [0xF000; 4];
+static EDGES : [u16; 2] = [0, 4];
+
+
+#[test]
+fn outline() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+    let f = font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       4,
+        height:         4,
+        fixed_pitch:    None,
+        notdef:         0,
+    };
+
+    vdi.rect((0, 0), (32, 32), &vdi::GRAY50);
+    let before = vdi.snapshot();
+
+    {
+        let mut t = font::TextContext::new(vdi, &f);
+        t.left = 8;
+        t.baseline = 12;
+        t.strike_fn = vdi::raster_op_bits(vdi::RasterOp::Or);
+        t.outline = true;
+        t.simple_put_char(0);
+        assert_eq!(t.left, 12);
+    }
+
+    // White glyph at (8, 8)-(12, 12), a black ring around it, dither elsewhere.
+    for y in 0..32 {
+        for x in 0..32 {
+            let glyph = (x >= 8) && (x < 12) && (y >= 8) && (y < 12);
+            let ring = (x >= 7) && (x < 13) && (y >= 7) && (y < 13) && !glyph;
+            let expected = if glyph { 255 } else if ring { 0 } else { before[(y * 32 + x) as usize] };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn outline_clipped_to_margins() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+    let f = font::Font {
+        bits:           &BITS,
+        left_edges:     &EDGES,
+        width:          16,
+        ascender:       4,
+        height:         4,
+        fixed_pitch:    None,
+        notdef:         0,
+    };
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+
+    {
+        let mut t = font::TextContext::new(vdi, &f);
+        t.left = 8;
+        t.baseline = 12;
+        t.strike_fn = vdi::raster_op_bits(vdi::RasterOp::NotSrc);
+        t.outline = true;
+        t.left_margin = 8;
+        t.right_margin = 12;
+        t.top_margin = 8;
+        t.bottom_margin = 12;
+        t.simple_put_char(0);
+    }
+
+    // The black glyph fills the margins exactly; its white halo is clipped away
+    // and the surrounding paper is untouched.
+    for y in 0..32 {
+        for x in 0..32 {
+            let glyph = (x >= 8) && (x < 12) && (y >= 8) && (y < 12);
+            assert_eq!(vdi.get_point((x, y)), if glyph { 0 } else { 255 }, "Point ({}, {})", x, y);
+        }
+    }
+}
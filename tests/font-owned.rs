@@ -0,0 +1,45 @@
+extern crate sdl2;
+extern crate gemini;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+
+use gemini::{vdi, font};
+
+
+fn render(f: &font::Font) -> Vec<u8> {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 16, "blah").unwrap();
+
+    let mut x = 0;
+    for c in b"Gemini".iter() {
+        x += f.draw_glyph(vdi, *c, (x, 4), 0b1010);
+    }
+    vdi.snapshot()
+}
+
+#[test]
+fn borrow() {
+    let owned = font::OwnedFont::from_font(font::borrow_system_font());
+    assert_eq!(owned.left_edges.len(), 257);
+
+    vdi::assert_snapshots_eq(
+        &render(&owned.borrow()),
+        &render(font::borrow_system_font())
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn round_trip() {
+    let owned = font::OwnedFont::from_font(font::borrow_system_font());
+    let serialized = serde_json::to_string(&owned).unwrap();
+    let restored : font::OwnedFont = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(restored, owned);
+    vdi::assert_snapshots_eq(
+        &render(&restored.borrow()),
+        &render(font::borrow_system_font())
+    );
+}
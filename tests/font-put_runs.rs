@@ -0,0 +1,66 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+fn context<'a>(vdi: &'a mut vdi::VDI) -> font::TextContext<'a> {
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = 4;
+    t.baseline = 11;
+    t.strike_fn = 0b0101;
+    t.advance_scale = Some(0x11000);
+    t
+}
+
+#[test]
+fn put_runs() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 256, 16, "blah").unwrap();
+    let plain = font::TextStyle::default();
+    let struck = font::TextStyle { strikethrough: true, .. plain };
+    let ghosted = font::TextStyle { ghosted: true, outline: true, .. plain };
+    let runs = [("normal ", plain), ("struck", struck), (" faded", ghosted)];
+
+    // Draw each run on its own, carrying the pen over by hand.
+    let expected_left;
+    {
+        let mut t = context(vdi);
+        for &(s, style) in runs.iter() {
+            t.strikethrough = style.strikethrough;
+            t.ghosted = style.ghosted;
+            t.outline = style.outline;
+            for chr in s.bytes() {
+                t.simple_put_char(chr);
+            }
+        }
+        expected_left = t.left;
+    }
+    let expected = vdi.snapshot();
+
+    vdi.rect((0, 0), (256, 16), &[0; 16]);
+    let mut t = context(vdi);
+    t.put_runs(&runs);
+
+    // 19 characters of 8.5 pixels each, rounded.
+    assert_eq!(t.left, expected_left);
+    assert_eq!(t.left, 4 + 162);
+    assert_eq!(t.style(), plain);
+    vdi::assert_snapshots_eq_with_width(&t.vdi.snapshot(), &expected, 256);
+}
+
+#[test]
+fn put_runs_restores_style() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 256, 16, "blah").unwrap();
+    let mut t = context(vdi);
+    let ghosted = font::TextStyle { ghosted: true, .. font::TextStyle::default() };
+
+    t.strikethrough = true;
+    t.put_runs(&[("ab", ghosted)]);
+    assert!(t.strikethrough);
+    assert!(!t.ghosted);
+}
@@ -0,0 +1,84 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+static PAPER : [u16; 16] = [0xFFFF; 16];
+
+
+fn put_a(vdi: &mut vdi::VDI, strikethrough: bool, right_margin: u16) {
+    vdi.rect((0, 0), (32, 16), &PAPER);
+
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = 4;
+    t.baseline = 11;
+    t.strike_fn = 0b0101;
+    t.strikethrough = strikethrough;
+    t.right_margin = right_margin;
+    t.simple_put_char(b'A');
+}
+
+#[test]
+fn strikethrough() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    // The system font's ascender is 7, so the middle of the cell is row 11 - 3.
+    put_a(vdi, false, 32);
+    let plain = vdi.snapshot();
+    assert!((4..12).any(|x| vdi.get_point((x, 8)) == 255));
+
+    put_a(vdi, true, 32);
+    for x in 0..32 {
+        let struck = (x >= 4) && (x < 12);
+        assert_eq!(vdi.get_point((x, 8)), if struck { 0 } else { 255 }, "Column {}", x);
+    }
+
+    // Nothing else about the glyph changes.
+    for y in (0..16).filter(|&y| y != 8) {
+        for x in 0..32 {
+            assert_eq!(vdi.get_point((x, y)), plain[(y * 32 + x) as usize]);
+        }
+    }
+}
+
+#[test]
+fn strikethrough_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    put_a(vdi, true, 8);
+    for x in 0..32 {
+        let struck = (x >= 4) && (x < 8);
+        assert_eq!(vdi.get_point((x, 8)), if struck { 0 } else { 255 }, "Column {}", x);
+    }
+}
+
+#[test]
+fn strikethrough_above_surface() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    // With the baseline on row 1, only the bottom of the cell shows,
+    // and the line through its middle would fall above the surface.
+    let draw = |vdi: &mut vdi::VDI, strikethrough: bool| {
+        vdi.rect((0, 0), (32, 16), &PAPER);
+        let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+        t.left = 4;
+        t.baseline = 1;
+        t.strike_fn = 0b0101;
+        t.strikethrough = strikethrough;
+        t.simple_put_char(b'A');
+        assert_eq!(t.left, 12);
+    };
+
+    draw(vdi, false);
+    let plain = vdi.snapshot();
+    draw(vdi, true);
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &plain, 32);
+}
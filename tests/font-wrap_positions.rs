@@ -0,0 +1,66 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::{vdi, font};
+
+
+fn wrap(left: u16, s: &str) -> Vec<usize> {
+    wrap_scaled(left, None, s)
+}
+
+fn wrap_scaled(left: u16, advance_scale: Option<u32>, s: &str) -> Vec<usize> {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+    let before = vdi.snapshot();
+
+    // The system font is 8 pixels wide, so ten characters fit between the margins.
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.left = left;
+    t.left_margin = 8;
+    t.right_margin = 88;
+    t.advance_scale = advance_scale;
+
+    let breaks = t.wrap_positions(s);
+    assert_eq!(t.left, left);
+    vdi::assert_snapshots_eq_with_width(&t.vdi.snapshot(), &before, 128);
+    breaks
+}
+
+#[test]
+fn wrap_positions() {
+    // "hello world" needs eleven cells; "world" moves to the next line.
+    assert_eq!(wrap(8, "hello world again"), vec![6, 12]);
+    assert_eq!(wrap(8, "hello"), Vec::<usize>::new());
+
+    // Trailing spaces never cause a wrap.
+    assert_eq!(wrap(8, "helloworld   "), Vec::<usize>::new());
+}
+
+#[test]
+fn wrap_positions_first_line_indent() {
+    // Starting mid-line leaves room for only five characters on the first line.
+    assert_eq!(wrap(48, "hello world"), vec![6]);
+    assert_eq!(wrap(48, "helloworld"), vec![0]);
+}
+
+#[test]
+fn wrap_positions_long_word() {
+    assert_eq!(wrap(8, "abcdefghijklmnopqrstuvwxy"), vec![10, 20]);
+    assert_eq!(wrap(8, "ab abcdefghijklmnop"), vec![3, 13]);
+}
+
+#[test]
+fn wrap_positions_newline() {
+    assert_eq!(wrap(8, "hi\nthere"), vec![3]);
+    assert_eq!(wrap(8, "hi\n\nthere"), vec![3, 4]);
+}
+
+#[test]
+fn wrap_positions_advance_scale() {
+    // Ten characters fit exactly at full size, but not once each is 8.5 pixels wide.
+    assert_eq!(wrap(8, "hello worl"), Vec::<usize>::new());
+    assert_eq!(wrap_scaled(8, Some(0x11000), "hello worl"), vec![6]);
+    assert_eq!(wrap_scaled(8, Some(0x11000), "abcdefghijklmnop"), vec![9]);
+}
@@ -0,0 +1,48 @@
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+static MOUSE_XOR : [u16; 16] = [
+    0b0000000000000000,
+    0b0100000000000000,
+    0b0011000000000000,
+    0b0011110000000000,
+    0b0001111100000000,
+    0b0001111111000000,
+    0b0000111111110000,
+    0b0000111111100000,
+    0b0000011111000000,
+    0b0000011111100000,
+    0b0000001101110000,
+    0b0000001000111000,
+    0b0000000000011100,
+    0b0000000000001100,
+    0b0000000000000000,
+    0b0000000000000000
+];
+
+
+#[test]
+fn bitmap_hit() {
+    // Pixel x of a row is bit x of its word.
+    assert!(vdi::bitmap_hit(&MOUSE_XOR, 16, (14, 1)));
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (15, 1)));
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (0, 0)));
+    assert!(vdi::bitmap_hit(&MOUSE_XOR, 16, (3, 12)));
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (1, 12)));
+    assert!(vdi::bitmap_hit(&MOUSE_XOR, 16, (9, 10)));
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (7, 10)));
+}
+
+#[test]
+fn bitmap_hit_out_of_range() {
+    // Beyond src_width, even though the word holds a set bit there.
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 12, (14, 1)));
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (16, 1)));
+
+    // Beyond the end of the bitmap.
+    assert!(!vdi::bitmap_hit(&MOUSE_XOR, 16, (3, 16)));
+    assert!(!vdi::bitmap_hit(&[], 16, (0, 0)));
+}
@@ -0,0 +1,56 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::RasterOp;
+
+
+static CHECKER : [u16; 16] = [
+    0xF0F0, 0xF0F0, 0xF0F0, 0xF0F0, 0x0F0F, 0x0F0F, 0x0F0F, 0x0F0F,
+    0xF0F0, 0xF0F0, 0xF0F0, 0xF0F0, 0x0F0F, 0x0F0F, 0x0F0F, 0x0F0F,
+];
+
+
+#[test]
+fn blit_surfaces() {
+    let sdl = sdl2::init().unwrap();
+    let src : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 100, 32, "blah").unwrap();
+    let dst : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 100, 32, "blah").unwrap();
+
+    src.rect((0, 0), (100, 32), &CHECKER);
+    vdi::blit_surfaces(src, (10, 2), (80, 20), dst, (5, 6), vdi::raster_op_bits(RasterOp::Copy));
+
+    for y in 0..32 {
+        for x in 0..100 {
+            let inside = (x >= 5) && (x < 85) && (y >= 6) && (y < 26);
+            let expected = if inside { src.get_point((x + 5, y - 4)) } else { 0 };
+            assert_eq!(dst.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn blit_surfaces_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let src : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+    let dst : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    src.rect((0, 0), (16, 16), &[0xFFFF; 16]);
+    dst.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+
+    // The region hangs off the source's right edge, and the destination's bottom;
+    // off-source pixels must not be copied as black.
+    vdi::blit_surfaces(src, (8, 8), (16, 16), dst, (0, 24), vdi::raster_op_bits(RasterOp::NotSrc));
+
+    for y in 0..32 {
+        for x in 0..32 {
+            let copied = (x < 8) && (y >= 24);
+            assert_eq!(dst.get_point((x, y)), if copied { 0 } else { 255 }, "Point ({}, {})", x, y);
+        }
+    }
+}
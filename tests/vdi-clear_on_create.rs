@@ -0,0 +1,21 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn clear_on_create() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2VdiBuilder::new(&sdl, 64, 48, "blah")
+            .clear_on_create(true)
+            .build().unwrap();
+
+    for y in 0..48 {
+        for x in 0..64 {
+            assert_eq!(vdi.get_point((x, y)), 0, "Point ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn color_table() {
+    let colors = vdi::color_table();
+
+    // Every value expands to itself in all four bytes, however they're ordered.
+    for pen in 0..256 {
+        let word = colors[pen];
+        for byte in 0..4 {
+            assert_eq!((word >> (byte * 8)) & 0xFF, pen as u32, "Pen {}, byte {}", pen, byte);
+        }
+    }
+}
+
+#[test]
+fn color_table_commit() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 16, 2, "blah").unwrap();
+
+    vdi.rect((0, 0), (16, 2), &vdi::GRAY50);
+    vdi.commit().unwrap();
+
+    let backbuffer = vdi.snapshot();
+    let pixels = vdi.with_pixels(|bits, span| {
+        let mut pixels = Vec::new();
+        for y in 0..2 {
+            pixels.extend_from_slice(&bits[y * span .. y * span + 16 * 4]);
+        }
+        pixels
+    }).unwrap();
+
+    for (i, &pen) in backbuffer.iter().enumerate() {
+        assert_eq!(&pixels[i * 4 .. i * 4 + 4], &[pen, pen, pen, pen], "Pixel {}", i);
+    }
+}
@@ -0,0 +1,28 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+use std::time;
+
+
+#[test]
+fn commit_at() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // At 10 frames per second, five frames span at least four frame periods.
+    let start = time::Instant::now();
+    for i in 0..5 {
+        vdi.draw_point((i, i), 255);
+        vdi.commit_at(10).unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    assert!(
+        elapsed >= time::Duration::from_millis(400),
+        "Five frames took only {:?}", elapsed
+    );
+}
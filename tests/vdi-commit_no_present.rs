@@ -0,0 +1,28 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn commit_no_present() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 16), &[0; 16]);
+    vdi.rect((8, 4), (16, 12), &[0xFFFF; 16]);
+    vdi.commit_no_present().unwrap();
+    vdi.present().unwrap();
+
+    let samples = vdi.with_pixels(|bits, span| {
+        (bits[0], bits[4 * span + 8 * 4], bits[11 * span + 15 * 4 + 3], bits[12 * span + 16 * 4])
+    }).unwrap();
+    assert_eq!(samples, (0, 255, 255, 0));
+    assert_eq!(vdi.get_point((8, 4)), 255);
+
+    // Presenting again, with nothing new committed, is harmless.
+    vdi.present().unwrap();
+    vdi.commit().unwrap();
+}
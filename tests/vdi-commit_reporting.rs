@@ -0,0 +1,20 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn commit_reporting() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 640, 480, "blah").unwrap();
+
+    vdi.draw_point((10, 10), 255);
+    assert_eq!(vdi.commit_reporting().unwrap(), (0, 0, 640, 480));
+
+    // The origin has no bearing on the reported region.
+    vdi.set_origin(-20, 30);
+    assert_eq!(vdi.commit_reporting().unwrap(), (0, 0, 640, 480));
+}
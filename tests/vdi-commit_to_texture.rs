@@ -0,0 +1,46 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+use sdl2::{pixels, render, video};
+
+
+fn host_renderer(sdl: &sdl2::Sdl) -> render::Renderer<'static> {
+    let video_subsystem = sdl.video().unwrap();
+    let w = video::WindowBuilder::new(&video_subsystem, "host", 64, 32).build().unwrap();
+    w.renderer().build().unwrap()
+}
+
+#[test]
+fn commit_to_texture() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 64, 32, "blah").unwrap();
+    let r = host_renderer(&sdl);
+    let mut t = r.create_texture(
+        pixels::PixelFormatEnum::ARGB8888,
+        render::TextureAccess::Streaming,
+        64, 32
+    ).unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+    vdi.commit_to_texture(&mut t).unwrap();
+}
+
+#[test]
+fn commit_to_texture_size_mismatch() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 64, 32, "blah").unwrap();
+    let r = host_renderer(&sdl);
+    let mut t = r.create_texture(
+        pixels::PixelFormatEnum::ARGB8888,
+        render::TextureAccess::Streaming,
+        32, 32
+    ).unwrap();
+
+    match vdi.commit_to_texture(&mut t) {
+        Err(vdi::VdiError::FromSdl(_)) => (),
+        _ => panic!("Expected an error for a texture of the wrong size"),
+    }
+}
@@ -3,6 +3,7 @@ extern crate gemini;
 
 
 use gemini::vdi;
+use gemini::vdi::RasterOp;
 
 
 static MOUSE_IOR : [u16; 16] = [
@@ -66,14 +67,14 @@ fn copy_line() {
             &MOUSE_IOR,     // source bits
             (639-x, x),     // Left/top edge of destination.
             (16, 16),       // Move a 16x16 block of bits.
-            0xEE            // Logical OR operation.
+            vdi::raster_op_bits(RasterOp::Or)
         );
         vdi.copy_rect_big_endian(
             (0, 0), 16,     // Left/top edge of source, and 16-pixels wide.
             &MOUSE_XOR,     // source bits
             (639-x, x),     // Left/top edge of destination.
             (16, 16),       // Move a 16x16 block of bits.
-            0x66            // Logical XOR operation.
+            vdi::raster_op_bits(RasterOp::Xor)
         );
         for y in 0..16 {
             vdi.copy_line(
@@ -85,7 +86,7 @@ fn copy_line() {
 
                 16,         // Move 16 pixels.
 
-                0xEE        // logical OR operation.
+                vdi::raster_op_bits(RasterOp::Or)
             );
             vdi.copy_line(
                 (0, y),     // left/top edge of source
@@ -96,7 +97,7 @@ fn copy_line() {
 
                 16,         // Move 16 pixels.
 
-                0x66        // logical XOR operation.
+                vdi::raster_op_bits(RasterOp::Xor)
             );
             vdi.copy_line_big_endian(
                 (0, y),     // left/top edge of source
@@ -107,7 +108,7 @@ fn copy_line() {
 
                 16,         // Move 16 pixels.
 
-                0xEE        // logical OR operation.
+                vdi::raster_op_bits(RasterOp::Or)
             );
             vdi.copy_line_big_endian(
                 (0, y),     // left/top edge of source
@@ -118,7 +119,7 @@ fn copy_line() {
 
                 16,         // Move 16 pixels.
 
-                0x66        // logical XOR operation.
+                vdi::raster_op_bits(RasterOp::Xor)
             );
         }
         vdi.commit().unwrap();
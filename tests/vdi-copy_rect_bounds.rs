@@ -0,0 +1,60 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+// Only four rows of what the caller will claim is a 16x16 bitmap.
+static SHORT : [u16; 4] = [0xFFFF; 4];
+
+
+fn count_set(vdi: &vdi::VDI) -> usize {
+    let mut set = 0;
+    for y in 0..32 {
+        for x in 0..32 {
+            if vdi.get_point((x, y)) != 0 {
+                set += 1;
+            }
+        }
+    }
+    set
+}
+
+#[test]
+fn copy_rect_short_source() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.copy_rect((0, 0), 16, &SHORT, (4, 4), (16, 16), 0b1010);
+    vdi.copy_rect_big_endian((0, 0), 16, &SHORT, (4, 20), (16, 16), 0b1010);
+
+    // Only the rows the source actually holds were copied.
+    for y in 0..32 {
+        for x in 0..32 {
+            let inside = (x >= 4) && (x < 20) && (((y >= 4) && (y < 8)) || ((y >= 20) && (y < 24)));
+            assert_eq!(vdi.get_point((x, y)), if inside { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn copy_line_out_of_range_source() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    // Starting past the source's width or height copies nothing.
+    vdi.copy_line((16, 0), 16, &SHORT, (0, 0), 16, 0b1010);
+    vdi.copy_line((40, 0), 16, &SHORT, (0, 0), 16, 0b1010);
+    vdi.copy_line((0, 4), 16, &SHORT, (0, 0), 16, 0b1010);
+    vdi.copy_line_big_endian((16, 0), 16, &SHORT, (0, 0), 16, 0b1010);
+    vdi.copy_line_big_endian((0, 9), 16, &SHORT, (0, 0), 16, 0b1010);
+    vdi.copy_rect((0, 2), 16, &SHORT, (0, 0), (16, 16), 0b1010);
+    assert_eq!(count_set(vdi), 2 * 16);
+
+    // An empty source is harmless too.
+    vdi.copy_rect((0, 0), 16, &[], (0, 16), (16, 16), 0b1010);
+    assert_eq!(count_set(vdi), 2 * 16);
+}
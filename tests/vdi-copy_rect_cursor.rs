@@ -0,0 +1,118 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::RasterOp;
+
+
+static MOUSE_IOR : [u16; 16] = [
+    0b1100000000000000,
+    0b1111000000000000,
+    0b0111110000000000,
+    0b0111111100000000,
+    0b0011111111000000,
+    0b0011111111110000,
+    0b0001111111111000,
+    0b0001111111110000,
+    0b0000111111100000,
+    0b0000111111110000,
+    0b0000011111111000,
+    0b0000011101111100,
+    0b0000001000111110,
+    0b0000000000011110,
+    0b0000000000001100,
+    0b0000000000000000
+];
+
+static MOUSE_XOR : [u16; 16] = [
+    0b0000000000000000,
+    0b0100000000000000,
+    0b0011000000000000,
+    0b0011110000000000,
+    0b0001111100000000,
+    0b0001111111000000,
+    0b0000111111110000,
+    0b0000111111100000,
+    0b0000011111000000,
+    0b0000011111100000,
+    0b0000001101110000,
+    0b0000001000111000,
+    0b0000000000011100,
+    0b0000000000001100,
+    0b0000000000000000,
+    0b0000000000000000
+];
+
+static DESKTOP : [u16; 16] = [
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+];
+
+
+// The masks above read naturally, left to right, as big-endian rows;
+// copy_rect wants them little-endian.
+fn little_endian(rows: &[u16]) -> Vec<u16> {
+    rows.iter().map(|&row| {
+        let mut flipped = 0u16;
+        for b in 0..16 {
+            if (row & (1 << b)) != 0 {
+                flipped |= 0x8000 >> b;
+            }
+        }
+        flipped
+    }).collect()
+}
+
+fn two_blits(vdi: &mut vdi::VDI, ior: &[u16], xor: &[u16], at: (u16, u16)) -> Vec<u8> {
+    vdi.rect((0, 0), (64, 48), &DESKTOP);
+    vdi.copy_rect((0, 0), 16, ior, at, (16, 16), vdi::raster_op_bits(RasterOp::Or));
+    vdi.copy_rect((0, 0), 16, xor, at, (16, 16), vdi::raster_op_bits(RasterOp::Xor));
+    vdi.snapshot()
+}
+
+fn one_pass(vdi: &mut vdi::VDI, ior: &[u16], xor: &[u16], at: (u16, u16)) -> Vec<u8> {
+    vdi.rect((0, 0), (64, 48), &DESKTOP);
+    vdi.copy_rect_cursor(ior, xor, 16, (0, 0), at, (16, 16));
+    vdi.snapshot()
+}
+
+#[test]
+fn copy_rect_cursor() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 48, "blah").unwrap();
+    let ior = little_endian(&MOUSE_IOR);
+    let xor = little_endian(&MOUSE_XOR);
+
+    // Fully on the surface, and hanging off the right and bottom edges.
+    for &at in [(5, 7), (56, 40)].iter() {
+        let expected = two_blits(vdi, &ior, &xor, at);
+        let actual = one_pass(vdi, &ior, &xor, at);
+        vdi::assert_snapshots_eq_with_width(&actual, &expected, 64);
+    }
+
+    // Hanging off the left and top edges.
+    vdi.set_origin(-6, -9);
+    let expected = two_blits(vdi, &ior, &xor, (0, 0));
+    let actual = one_pass(vdi, &ior, &xor, (0, 0));
+    vdi::assert_snapshots_eq_with_width(&actual, &expected, 64);
+}
+
+#[test]
+fn copy_rect_cursor_far_source() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 48, "blah").unwrap();
+    let ior = little_endian(&MOUSE_IOR);
+    let xor = little_endian(&MOUSE_XOR);
+
+    // A source origin near the end of the coordinate space reads nothing, rather than overflowing.
+    vdi.rect((0, 0), (64, 48), &DESKTOP);
+    let before = vdi.snapshot();
+    vdi.copy_rect_cursor(&ior, &xor, 16, (0xFFF8, 0xFFF8), (5, 7), (16, 16));
+    vdi.copy_rect_cursor(&ior, &xor, 16, (0, 0xFFF8), (5, 7), (16, 16));
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &before, 64);
+}
@@ -0,0 +1,87 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+fn count_set(vdi: &vdi::VDI, left: u16, right: u16, top: u16, bottom: u16) -> usize {
+    let mut set = 0;
+    for y in top..bottom {
+        for x in left..right {
+            if vdi.get_point((x, y)) != 0 {
+                set += 1;
+            }
+        }
+    }
+    set
+}
+
+#[test]
+fn copy_rect_error_diffused() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+
+    // A horizontal ramp from black to nearly white, 64x32 pixels.
+    let mut ramp = Vec::new();
+    for _ in 0..32 {
+        for x in 0..64 {
+            ramp.push((x * 4) as u8);
+        }
+    }
+
+    vdi.copy_rect_error_diffused((0, 0), 64, &ramp, (10, 10), (64, 32));
+
+    // Brighter bands of the ramp come out with more white pixels.
+    let bands : Vec<usize> = (0..4)
+        .map(|b| count_set(vdi, 10 + b * 16, 26 + b * 16, 10, 42))
+        .collect();
+    for b in 1..4 {
+        assert!(bands[b] > bands[b - 1], "Band densities {:?}", bands);
+    }
+
+    // The darkest band is nearly black; the brightest nearly white.
+    assert!(bands[0] < 16 * 32 / 8, "Band densities {:?}", bands);
+    assert!(bands[3] > 16 * 32 * 3 / 4, "Band densities {:?}", bands);
+
+    // Nothing lands outside the destination.
+    assert_eq!(count_set(vdi, 0, 128, 0, 10), 0);
+    assert_eq!(count_set(vdi, 74, 128, 0, 64), 0);
+}
+
+#[test]
+fn copy_rect_error_diffused_solid() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    let white = vec![255; 16 * 16];
+    vdi.copy_rect_error_diffused((0, 0), 16, &white, (0, 0), (16, 16));
+    assert_eq!(count_set(vdi, 0, 16, 0, 16), 256);
+
+    // Mid-gray comes out close to half set.
+    let gray = vec![128; 16 * 16];
+    vdi.copy_rect_error_diffused((0, 0), 16, &gray, (16, 16), (16, 16));
+    let set = count_set(vdi, 16, 32, 16, 32);
+    assert!((set >= 112) && (set <= 144), "{} pixels set", set);
+}
+
+#[test]
+fn copy_rect_error_diffused_far_edge() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    // A region running past the end of the coordinate space draws only what fits.
+    let white = vec![255; 16 * 16];
+    vdi.copy_rect_error_diffused((0, 0), 16, &white, (65530, 65530), (16, 16));
+    assert_eq!(count_set(vdi, 0, 32, 0, 32), 0);
+
+    // With the origin moved, the part that fits still lands on the surface.
+    vdi.set_origin(-32760, -32760);
+    vdi.copy_rect_error_diffused((0, 0), 16, &white, (32790, 32790), (16, 16));
+    vdi.set_origin(0, 0);
+    assert_eq!(count_set(vdi, 30, 32, 30, 32), 4);
+    assert_eq!(count_set(vdi, 0, 30, 0, 30), 0);
+}
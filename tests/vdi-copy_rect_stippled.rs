@@ -0,0 +1,71 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+static SQUARE : [u16; 8] = [0x00FF; 8];
+
+
+#[test]
+fn copy_rect_stippled() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.copy_rect_stippled((0, 0), 16, &SQUARE, (5, 6), (16, 8), 255);
+
+    let mut set = 0;
+    for y in 0..32 {
+        for x in 0..32 {
+            let in_square = (x >= 5) && (x < 13) && (y >= 6) && (y < 14);
+            let expected = if in_square && ((x ^ y) & 1 == 0) { 255 } else { 0 };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+            if expected != 0 {
+                set += 1;
+            }
+        }
+    }
+
+    // Exactly half the square survives the stipple.
+    assert_eq!(set, 32);
+}
+
+#[test]
+fn copy_rect_stippled_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+    vdi.copy_rect_stippled((0, 0), 16, &SQUARE, (28, 28), (16, 8), 0);
+
+    for y in 28..32 {
+        for x in 28..32 {
+            let expected = if (x ^ y) & 1 == 0 { 0 } else { 255 };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn copy_rect_stippled_far_source() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    // A source origin near the end of the coordinate space reads nothing, rather than overflowing.
+    vdi.copy_rect_stippled((0xFFF8, 0xFFF8), 16, &SQUARE, (0, 0), (16, 16), 255);
+    vdi.copy_rect_stippled((0, 0xFFF8), 16, &SQUARE, (0, 0), (16, 16), 255);
+    vdi::assert_snapshots_eq(&vdi.snapshot(), &[0; 32 * 32]);
+
+    // Columns past the source's width read nothing either.
+    vdi.copy_rect_stippled((4, 0), 8, &SQUARE, (0, 0), (16, 8), 255);
+    for y in 0..8 {
+        for x in 0..16 {
+            let expected = if (x < 4) && ((x ^ y) & 1 == 0) { 255 } else { 0 };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
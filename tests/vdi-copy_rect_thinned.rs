@@ -0,0 +1,58 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::RasterOp;
+
+
+// An 8x8 bitmap holding a one pixel wide vertical stroke in column 3,
+// and a one pixel tall horizontal stroke along row 5.
+static STROKES : [u16; 8] = [
+    0x0008, 0x0008, 0x0008, 0x0008,
+    0x0008, 0x00FF, 0x0008, 0x0008,
+];
+
+
+#[test]
+fn copy_rect_thinned() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    vdi.copy_rect_thinned((0, 0), 8, &STROKES, (2, 2), (4, 4), vdi::raster_op_bits(RasterOp::Copy));
+
+    // Sampling every other pixel would miss both strokes entirely;
+    // thinning keeps them, a pixel wide, in column 1 and row 2 of the result.
+    for y in 0..4 {
+        for x in 0..4 {
+            let expected = if (x == 1) || (y == 2) { 255 } else { 0 };
+            assert_eq!(vdi.get_point((x + 2, y + 2)), expected, "Point ({}, {})", x, y);
+        }
+    }
+
+    // Nothing lands outside the destination rectangle.
+    for y in 0..16 {
+        for x in 0..16 {
+            if (x < 2) || (x >= 6) || (y < 2) || (y >= 6) {
+                assert_eq!(vdi.get_point((x, y)), 0, "Point ({}, {})", x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn copy_rect_thinned_offset() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    // Starting one column in shifts the stroke into the first destination column,
+    // and the source runs out after the fourth.
+    vdi.copy_rect_thinned((3, 0), 8, &STROKES, (0, 0), (6, 4), vdi::raster_op_bits(RasterOp::Copy));
+
+    let row0 : Vec<u8> = (0..6).map(|x| vdi.get_point((x, 0))).collect();
+    assert_eq!(row0, vec![255, 0, 0, 0, 0, 0]);
+    let row2 : Vec<u8> = (0..6).map(|x| vdi.get_point((x, 2))).collect();
+    assert_eq!(row2, vec![255, 255, 255, 0, 0, 0]);
+}
@@ -0,0 +1,62 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+fn set_points(vdi: &vdi::VDI) -> Vec<(u16, u16)> {
+    let mut points = Vec::new();
+    for y in 0..32 {
+        for x in 0..32 {
+            if vdi.get_point((x, y)) != 0 {
+                points.push((x, y));
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn crosshair() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.crosshair((10, 12), 3, 0xFFFF);
+
+    let mut expected = Vec::new();
+    for y in 0..32 {
+        for x in 0..32 {
+            let on_hline = (y == 12) && (x >= 7) && (x <= 13);
+            let on_vline = (x == 10) && (y >= 9) && (y <= 15);
+            if on_hline || on_vline {
+                expected.push((x, y));
+            }
+        }
+    }
+    assert_eq!(set_points(vdi), expected);
+}
+
+#[test]
+fn crosshair_single_pixel() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.crosshair((5, 6), 0, 0xFFFF);
+    assert_eq!(set_points(vdi), vec![(5, 6)]);
+}
+
+#[test]
+fn crosshair_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.crosshair((1, 30), 4, 0xFFFF);
+    assert_eq!(
+        set_points(vdi),
+        vec![(1, 26), (1, 27), (1, 28), (1, 29), (0, 30), (1, 30), (2, 30), (3, 30), (4, 30), (5, 30), (1, 31)]
+    );
+}
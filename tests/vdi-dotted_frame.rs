@@ -0,0 +1,81 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn dotted_frame() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.dotted_frame((10, 10), (30, 20), 3, 2, 255);
+
+    // The top edge begins with a full dash at the corner.
+    let top : Vec<u8> = (10..30).map(|x| vdi.get_point((x, 10))).collect();
+    assert_eq!(
+        top,
+        vec![255, 255, 255, 0, 0, 255, 255, 255, 0, 0, 255, 255, 255, 0, 0, 255, 255, 255, 0, 0]
+    );
+
+    // The cadence carries around the top-right corner.  The top edge is
+    // exactly four cycles long, so the right edge begins with a fresh dash...
+    let right : Vec<u8> = (11..20).map(|y| vdi.get_point((29, y))).collect();
+    assert_eq!(right, vec![255, 255, 255, 0, 0, 255, 255, 255, 0]);
+
+    // ...and so on around all 56 perimeter pixels:
+    // eleven 5-pixel cycles, plus the first pixel of a twelfth.
+    let mut set = 0;
+    for y in 0..64 {
+        for x in 0..64 {
+            if vdi.get_point((x, y)) != 0 {
+                set += 1;
+            }
+        }
+    }
+    assert_eq!(set, 11 * 3 + 1);
+
+    // The interior is untouched.
+    assert_eq!(vdi.get_point((20, 15)), 0);
+}
+
+#[test]
+fn dotted_frame_solid() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.frame((10, 10), (30, 20), 0xFFFF);
+    let framed = vdi.snapshot();
+
+    vdi.rect((0, 0), (64, 64), &[0; 16]);
+    vdi.dotted_frame((10, 10), (30, 20), 1, 0, 255);
+
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &framed, 64);
+}
+
+#[test]
+fn dotted_frame_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.dotted_frame((10, 10), (30, 20), 3, 2, 255);
+    let whole = vdi.snapshot();
+
+    // Pixels falling off the surface still count towards the cadence,
+    // so the visible dashes stay registered to the top-left corner.
+    vdi.rect((0, 0), (64, 64), &[0; 16]);
+    vdi.set_origin(-14, -14);
+    vdi.dotted_frame((10, 10), (30, 20), 3, 2, 255);
+    vdi.set_origin(0, 0);
+
+    for y in 0..50 {
+        for x in 0..50 {
+            let expected = whole[(y + 14) * 64 + x + 14];
+            assert_eq!(vdi.get_point((x as u16, y as u16)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
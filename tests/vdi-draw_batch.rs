@@ -0,0 +1,116 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn draw_batch() {
+    let sdl = sdl2::init().unwrap();
+    let mut batched = vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+    let mut unbatched = vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+
+    batched.set_origin(-3, 2);
+    unbatched.set_origin(-3, 2);
+
+    {
+        let mut batch = batched.begin_batch();
+        for i in 0..1000u32 {
+            let at = (((i * 37) % 140) as u16, ((i * 11) % 70) as u16);
+            batch.draw_point(at, if i % 3 == 0 { 0 } else { 255 });
+            assert_eq!(batch.get_point(at), unbatched_point(at, i));
+        }
+        batch.rect((60, 30), (90, 50), &vdi::GRAY50);
+        batch.hline((0, 5), 200, 0x1248);
+        batch.hline_phased((10, 6), 100, 0x1248, 5);
+        batch.vline((7, 0), 60, 0xF0F0);
+        batch.invert_line((20, 40), 70);
+        batch.end_batch();
+    }
+    batched.commit().unwrap();
+
+    for i in 0..1000u32 {
+        let at = (((i * 37) % 140) as u16, ((i * 11) % 70) as u16);
+        unbatched.draw_point(at, if i % 3 == 0 { 0 } else { 255 });
+    }
+    unbatched.rect((60, 30), (90, 50), &vdi::GRAY50);
+    unbatched.hline((0, 5), 200, 0x1248);
+    unbatched.hline_phased((10, 6), 100, 0x1248, 5);
+    unbatched.vline((7, 0), 60, 0xF0F0);
+    unbatched.invert_line((20, 40), 70);
+
+    vdi::assert_snapshots_eq_with_width(&batched.snapshot(), &unbatched.snapshot(), 128);
+}
+
+// What a point drawn within the batch reads back as, once translated by the origin.
+fn unbatched_point(at: (u16, u16), i: u32) -> u8 {
+    let (x, y) = (at.0 as i32 - 3, at.1 as i32 + 2);
+    if (x < 0) || (x >= 128) || (y >= 64) || (i % 3 == 0) { 0 } else { 255 }
+}
+
+static ARROW : [u16; 4] = [0x0001, 0x0003, 0x0007, 0x000F];
+
+// Draws the same blits, frames, and inversions either through a batch or through the trait,
+// some of them crossing the surface's edges.
+fn blits(batched: bool) -> vdi::SDL2Vdi {
+    let sdl = sdl2::init().unwrap();
+    let mut surface = vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+    surface.set_origin(4, -2);
+
+    if batched {
+        let mut batch = surface.begin_batch();
+        batch.frame((0, 0), (8, 8), 0xFFFF);
+        batch.invert_rect((26, 0), (40, 6));
+        batch.copy_line((0, 3), 4, &ARROW, (10, 4), 4, vdi::raster_op_bits(vdi::RasterOp::Copy));
+        batch.copy_rect((0, 0), 4, &ARROW, (25, 14), (4, 4), vdi::raster_op_bits(vdi::RasterOp::Copy));
+        batch.end_batch();
+    }
+    else {
+        surface.frame((0, 0), (8, 8), 0xFFFF);
+        surface.invert_rect((26, 0), (40, 6));
+        surface.copy_line((0, 3), 4, &ARROW, (10, 4), 4, vdi::raster_op_bits(vdi::RasterOp::Copy));
+        surface.copy_rect((0, 0), 4, &ARROW, (25, 14), (4, 4), vdi::raster_op_bits(vdi::RasterOp::Copy));
+    }
+
+    surface
+}
+
+#[test]
+fn draw_batch_origin_and_clipping() {
+    let mut batched = blits(true);
+    vdi::assert_snapshots_eq_with_width(&batched.snapshot(), &blits(false).snapshot(), 32);
+
+    // Read back through the surface, in surface coordinates.
+    batched.set_origin(0, 0);
+    let point = |x: u16, y: u16| batched.get_point((x, y));
+
+    // The frame spans surface columns 4 to 11 and rows 0 to 5, its top edge falling off the surface.
+    assert_eq!(point(4, 0), 255);
+    assert_eq!(point(11, 5), 255);
+    assert_eq!(point(5, 0), 0);
+    assert_eq!(point(5, 5), 255);
+    assert_eq!(point(6, 3), 0);
+
+    // The inversion is clipped to surface columns 30 and 31, rows 0 to 3.
+    for y in 0..8 {
+        for x in 28..32 {
+            let inverted = (x >= 30) && (y < 4);
+            assert_eq!(point(x, y), if inverted { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+
+    // The copied row of the arrow is four pixels wide, at surface (14, 2).
+    for x in 12..20 {
+        assert_eq!(point(x, 2), if (x >= 14) && (x < 18) { 255 } else { 0 }, "Column {}", x);
+    }
+
+    // The arrow lands at surface (29, 12), its rightmost column falling off the surface.
+    assert_eq!(point(29, 12), 255);
+    assert_eq!(point(30, 12), 0);
+    assert_eq!(point(30, 13), 255);
+    assert_eq!(point(31, 13), 0);
+    assert_eq!(point(31, 14), 255);
+    assert_eq!(point(31, 15), 255);
+}
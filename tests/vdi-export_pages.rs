@@ -0,0 +1,41 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn export_pages() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 4, 12, "blah").unwrap();
+
+    // Column 0 is solid, column 1 has only its top pixel set,
+    // column 2 only row 8, and column 3 every other row.
+    vdi.vline((0, 0), 12, 0xFFFF);
+    vdi.draw_point((1, 0), 255);
+    vdi.draw_point((2, 8), 255);
+    vdi.vline((3, 0), 12, 0x5555);
+
+    assert_eq!(vdi.export_pages(8), vec![
+        0xFF, 0x80, 0x00, 0xAA,
+        0xF0, 0x00, 0x80, 0xA0,
+    ]);
+}
+
+#[test]
+fn export_short_pages() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 2, 5, "blah").unwrap();
+
+    vdi.vline((0, 0), 5, 0xFFFF);
+    vdi.draw_point((1, 4), 255);
+
+    // Pages two pixels tall use the top two bits of each byte.
+    assert_eq!(vdi.export_pages(2), vec![
+        0xC0, 0x00,
+        0xC0, 0x00,
+        0x80, 0x80,
+    ]);
+}
@@ -0,0 +1,29 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn fill_rects() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    let bars = [
+        ((2, 40), (10, 64)),
+        ((12, 20), (20, 64)),
+        ((22, 50), (90, 70)),    // hangs off the surface
+    ];
+    vdi.fill_rects(&bars, &[0xFFFF; 16]);
+
+    for y in 0..64 {
+        for x in 0..64 {
+            let inside = bars.iter().any(|&((l, t), (r, b))| {
+                (x >= l) && (x < r) && (y >= t) && (y < b)
+            });
+            assert_eq!(vdi.get_point((x, y)), if inside { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn frame_edges() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.frame_edges((8, 8), (40, 24), 0x5555, 0xFFFF, 0x3333, 0x0F0F);
+
+    // The top edge is dotted and the bottom solid, each aligned to the surface.
+    for x in 9..39 {
+        let dot = if (x & 1) == 0 { 255 } else { 0 };
+        assert_eq!(vdi.get_point((x, 8)), dot, "Top, column {}", x);
+        assert_eq!(vdi.get_point((x, 23)), 255, "Bottom, column {}", x);
+    }
+
+    // The sides run the full height, corners included.
+    for y in 8..24 {
+        let left = if (0x3333 >> (y & 15)) & 1 != 0 { 255 } else { 0 };
+        let right = if (0x0F0F >> (y & 15)) & 1 != 0 { 255 } else { 0 };
+        assert_eq!(vdi.get_point((8, y)), left, "Left, row {}", y);
+        assert_eq!(vdi.get_point((39, y)), right, "Right, row {}", y);
+    }
+
+    assert_eq!(vdi.get_point((20, 16)), 0);
+}
+
+#[test]
+fn frame_edges_uniform() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // With every edge alike, it's just a frame, clipped the same way.
+    vdi.frame((40, 50), (80, 70), 0xAAAA);
+    let framed = vdi.snapshot();
+
+    vdi.rect((0, 0), (64, 64), &[0; 16]);
+    vdi.frame_edges((40, 50), (80, 70), 0xAAAA, 0xAAAA, 0xAAAA, 0xAAAA);
+
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &framed, 64);
+}
+
+#[test]
+fn frame_edges_degenerate() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    // Empty frames, including ones touching the top-left corner, draw nothing.
+    vdi.frame_edges((0, 0), (0, 0), 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF);
+    vdi.frame_edges((0, 0), (10, 0), 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF);
+    vdi.frame_edges((0, 4), (0, 10), 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF);
+    vdi::assert_snapshots_eq(&vdi.snapshot(), &[0; 16 * 16]);
+
+    // A frame around the whole surface lands on its outermost pixels.
+    vdi.frame_edges((16, 16), (0, 0), 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF);
+    for y in 0..16 {
+        for x in 0..16 {
+            let edge = (x == 0) || (y == 0) || (x == 15) || (y == 15);
+            assert_eq!(vdi.get_point((x, y)), if edge { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn get_hline_bits() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 100, 8, "blah").unwrap();
+
+    // hline aligns its pattern with the surface, so starting at x=16
+    // puts bit 0 of the pattern on the first pixel.
+    vdi.hline((16, 3), 32, 0xC0A5);
+    vdi.hline((32, 3), 48, 0x0F0F);
+    vdi.hline((48, 3), 64, 0x8001);
+    vdi.hline((64, 3), 80, 0xFFFF);
+
+    assert_eq!(vdi.get_hline_bits((16, 3), 16), 0xC0A5);
+    assert_eq!(vdi.get_hline_bits((16, 3), 64), 0xFFFF80010F0FC0A5);
+    assert_eq!(vdi.get_hline_bits((20, 3), 8), 0x0A);
+
+    // Pixels past the requested width, or past the edge of the surface, read as 0.
+    assert_eq!(vdi.get_hline_bits((16, 3), 4), 0x5);
+    assert_eq!(vdi.get_hline_bits((64, 3), 64), 0xFFFF);
+    vdi.hline((64, 3), 100, 0xFFFF);
+    assert_eq!(vdi.get_hline_bits((64, 3), 64), 0xFFFFFFFFF);
+    assert_eq!(vdi.get_hline_bits((16, 8), 64), 0);
+    assert_eq!(vdi.get_hline_bits((100, 3), 64), 0);
+
+    // Bits agree with get_point.
+    let bits = vdi.get_hline_bits((16, 3), 64);
+    for i in 0..64 {
+        let p = vdi.get_point((16 + i, 3));
+        assert_eq!((bits >> i) & 1 != 0, p == 255, "Pixel {}", i);
+    }
+}
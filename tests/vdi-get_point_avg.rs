@@ -0,0 +1,48 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn get_point_avg() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 8, "blah").unwrap();
+
+    // Black on the left half, white on the right.
+    vdi.rect((0, 0), (16, 8), &[0; 16]);
+    vdi.rect((16, 0), (32, 8), &[0xFFFF; 16]);
+
+    // A box straddling the boundary is half covered.
+    assert_eq!(vdi.get_point_avg((16, 4), 4), 128);
+    assert_eq!(vdi.get_point_avg((16, 4), 2), 128);
+
+    // A box to one side sees only black or only white.
+    assert_eq!(vdi.get_point_avg((8, 4), 5), 0);
+    assert_eq!(vdi.get_point_avg((24, 4), 5), 255);
+
+    // One white column in three.
+    assert_eq!(vdi.get_point_avg((15, 4), 3), 85);
+
+    // Small kernels read the point itself.
+    assert_eq!(vdi.get_point_avg((15, 4), 0), 0);
+    assert_eq!(vdi.get_point_avg((16, 4), 1), 255);
+}
+
+#[test]
+fn get_point_avg_clamped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 8, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 8), &[0; 16]);
+    vdi.rect((16, 0), (32, 8), &[0xFFFF; 16]);
+
+    // Only the part of the box on the surface counts.
+    assert_eq!(vdi.get_point_avg((0, 0), 3), 0);
+    assert_eq!(vdi.get_point_avg((31, 7), 3), 255);
+    assert_eq!(vdi.get_point_avg((16, 0), 64), 128);
+    assert_eq!(vdi.get_point_avg((100, 100), 3), 0);
+}
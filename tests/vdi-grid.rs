@@ -0,0 +1,45 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn grid() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 128, "blah").unwrap();
+
+    vdi.grid((0, 0), (100, 100), (10, 10), 0xFFFF);
+
+    for row in 0..10 {
+        for col in 0..10 {
+            let (x, y) = (col * 10, row * 10);
+
+            // Intersections and the lines between them are set...
+            assert_eq!(vdi.get_point((x, y)), 255, "Intersection ({}, {})", x, y);
+            assert_eq!(vdi.get_point((x + 5, y)), 255, "Line at ({}, {})", x + 5, y);
+            assert_eq!(vdi.get_point((x, y + 5)), 255, "Line at ({}, {})", x, y + 5);
+
+            // ...while cell interiors are not.
+            assert_eq!(vdi.get_point((x + 5, y + 5)), 0, "Cell at ({}, {})", x + 5, y + 5);
+        }
+    }
+
+    // Nothing spills past the far edges.
+    assert_eq!(vdi.get_point((100, 50)), 0);
+    assert_eq!(vdi.get_point((50, 100)), 0);
+}
+
+#[test]
+fn grid_one_axis() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 128, "blah").unwrap();
+
+    vdi.grid((0, 0), (100, 100), (0, 10), 0xFFFF);
+
+    assert_eq!(vdi.get_point((5, 10)), 255);
+    assert_eq!(vdi.get_point((10, 5)), 0);
+}
@@ -31,3 +31,25 @@ fn hline() {
     }
 }
 
+#[test]
+fn hline_solid_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 300, 4, "blah").unwrap();
+
+    vdi.rect((0, 0), (300, 4), &vdi::GRAY50);
+
+    // Solid lines, hanging off both ends of the surface, with reversed endpoints.
+    vdi.set_origin(-20, 0);
+    vdi.hline((330, 1), 10, 0xFFFF);
+    vdi.hline((330, 2), 10, 0x0000);
+    vdi.set_origin(0, 0);
+
+    for x in 0..300 {
+        assert_eq!(vdi.get_point((x, 1)), 255, "Column {}", x);
+        assert_eq!(vdi.get_point((x, 2)), 0, "Column {}", x);
+
+        let dither = if (vdi::GRAY50[0] >> (x & 15)) & 1 != 0 { 255 } else { 0 };
+        assert_eq!(vdi.get_point((x, 0)), dither, "Column {}", x);
+    }
+}
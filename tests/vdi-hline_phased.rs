@@ -0,0 +1,52 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+fn row(vdi: &vdi::VDI, y: u16, width: u16) -> Vec<u8> {
+    (0..width).map(|x| vdi.get_point((x, y))).collect()
+}
+
+#[test]
+fn hline_phased() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 48, 4, "blah").unwrap();
+
+    // Phase 0 at x=5 puts bit 0 of the pattern at x=5, unlike hline.
+    vdi.hline_phased((5, 0), 37, 0x0001, 0);
+    vdi.hline_phased((5, 1), 37, 0x0001, 3);
+
+    let first = row(vdi, 0, 48);
+    let second = row(vdi, 1, 48);
+    for x in 0..48 {
+        let expected = (x >= 5) && (x < 37) && ((x - 5) % 16 == 0);
+        assert_eq!(first[x], if expected { 255 } else { 0 }, "Phase 0, column {}", x);
+
+        // Advancing the phase by 3 slides the pattern 3 pixels to the left.
+        let expected = (x >= 5) && (x < 37) && ((x - 5 + 3) % 16 == 0);
+        assert_eq!(second[x], if expected { 255 } else { 0 }, "Phase 3, column {}", x);
+    }
+}
+
+#[test]
+fn hline_phased_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 48, 4, "blah").unwrap();
+
+    // The same line, once with its left end clipped off the surface;
+    // the visible pixels must agree.
+    vdi.hline_phased((10, 0), 40, 0x1234, 7);
+    vdi.set_origin(-14, 1);
+    vdi.hline_phased((10, 0), 40, 0x1234, 7);
+    vdi.set_origin(0, 0);
+
+    let whole = row(vdi, 0, 48);
+    let clipped = row(vdi, 1, 48);
+    for x in 0..26 {
+        assert_eq!(clipped[x], whole[x + 14], "Column {}", x);
+    }
+}
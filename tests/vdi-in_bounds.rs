@@ -0,0 +1,28 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn in_bounds() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    assert!(vdi.in_bounds((0, 0)));
+    assert!(vdi.in_bounds((31, 0)));
+    assert!(vdi.in_bounds((0, 15)));
+    assert!(vdi.in_bounds((31, 15)));
+    assert!(!vdi.in_bounds((32, 15)));
+    assert!(!vdi.in_bounds((31, 16)));
+    assert!(!vdi.in_bounds((65535, 65535)));
+
+    // Unlike point_visible, the origin doesn't matter.
+    vdi.set_origin(-8, 4);
+    assert!(vdi.in_bounds((0, 0)));
+    assert!(vdi.in_bounds((31, 15)));
+    assert!(!vdi.in_bounds((32, 0)));
+    assert!(!vdi.in_bounds((0, 16)));
+}
@@ -0,0 +1,62 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+// A 16x16 mask holding a disc of radius 6 centered on (8, 8).
+fn disc() -> Vec<u16> {
+    (0..16).map(|y| {
+        let mut row = 0u16;
+        for x in 0..16 {
+            if in_disc(x, y) {
+                row |= 1 << x;
+            }
+        }
+        row
+    }).collect()
+}
+
+fn in_disc(x: i32, y: i32) -> bool {
+    (x - 8) * (x - 8) + (y - 8) * (y - 8) <= 36
+}
+
+
+#[test]
+fn invert_masked() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 48, 32, "blah").unwrap();
+    let mask = disc();
+
+    vdi.rect((0, 0), (48, 32), &[0xFFFF; 16]);
+    let before = vdi.snapshot();
+
+    vdi.invert_masked(&mask, 16, (10, 4), (16, 16));
+    for y in 0..32 {
+        for x in 0..48 {
+            let inside = (x >= 10) && (x < 26) && (y >= 4) && (y < 20) && in_disc(x - 10, y - 4);
+            assert_eq!(vdi.get_point((x as u16, y as u16)), if inside { 0 } else { 255 }, "Point ({}, {})", x, y);
+        }
+    }
+
+    vdi.invert_masked(&mask, 16, (10, 4), (16, 16));
+    vdi::assert_snapshots_eq_with_width(&before, &vdi.snapshot(), 48);
+}
+
+#[test]
+fn invert_masked_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 20, 20, "blah").unwrap();
+    let mask = disc();
+
+    vdi.invert_masked(&mask, 16, (12, 12), (16, 16));
+    for y in 0..20 {
+        for x in 0..20 {
+            let inside = (x >= 12) && (y >= 12) && in_disc(x - 12, y - 12);
+            assert_eq!(vdi.get_point((x as u16, y as u16)), if inside { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
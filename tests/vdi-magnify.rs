@@ -0,0 +1,53 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn magnify() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // A 2x2 checker at (1, 1): white, black / black, white.
+    vdi.draw_point((1, 1), 255);
+    vdi.draw_point((2, 2), 255);
+
+    vdi.magnify((1, 1), (2, 2), (20, 30), 4);
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let source = vdi.get_point((1 + x / 4, 1 + y / 4));
+            assert_eq!(vdi.get_point((20 + x, 30 + y)), source, "Point ({}, {})", x, y);
+        }
+    }
+
+    // Nothing else was drawn.
+    let mut set = 0;
+    for y in 0..64 {
+        for x in 0..64 {
+            if vdi.get_point((x, y)) != 0 {
+                set += 1;
+            }
+        }
+    }
+    assert_eq!(set, 2 + 2 * 16);
+}
+
+#[test]
+fn magnify_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.rect((0, 0), (4, 4), &[0xFFFF; 16]);
+    vdi.magnify((0, 0), (4, 4), (56, 56), 4);
+
+    for y in 56..64 {
+        for x in 56..64 {
+            assert_eq!(vdi.get_point((x, y)), 255, "Point ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+static PAPER : [u16; 16] = [0xFFFF; 16];
+
+
+#[test]
+fn origin() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    assert_eq!(vdi.origin(), (0, 0));
+
+    vdi.set_origin(10, 10);
+    assert_eq!(vdi.origin(), (10, 10));
+    vdi.draw_point((0, 0), 255);
+    assert_eq!(vdi.get_point((0, 0)), 255);
+
+    vdi.set_origin(0, 0);
+    assert_eq!(vdi.get_point((10, 10)), 255);
+    assert_eq!(vdi.get_point((0, 0)), 0);
+}
+
+#[test]
+fn negative_origin() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // A 10x10 box, half of which hangs off the top and left edges.
+    vdi.set_origin(-5, -5);
+    vdi.rect((0, 0), (10, 10), &PAPER);
+    vdi.draw_point((2, 2), 255);
+    assert_eq!(vdi.get_point((2, 2)), 0);
+
+    vdi.set_origin(0, 0);
+    for y in 0..64 {
+        for x in 0..64 {
+            let p = vdi.get_point((x, y));
+            if (x < 5) && (y < 5) {
+                assert_eq!(p, 255, "Point ({}, {}) = {}", x, y, p);
+            }
+            else {
+                assert_eq!(p, 0, "Point ({}, {}) = {}", x, y, p);
+            }
+        }
+    }
+}
+
+#[test]
+fn copy_rect_negative_origin() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // Only the lower-right 12x12 corner of this 16x16 block should appear.
+    vdi.set_origin(-4, -4);
+    vdi.copy_rect((0, 0), 16, &PAPER, (0, 0), (16, 16), 0b1010);
+
+    vdi.set_origin(0, 0);
+    for y in 0..64 {
+        for x in 0..64 {
+            let p = vdi.get_point((x, y));
+            if (x < 12) && (y < 12) {
+                assert_eq!(p, 255, "Point ({}, {}) = {}", x, y, p);
+            }
+            else {
+                assert_eq!(p, 0, "Point ({}, {}) = {}", x, y, p);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use sdl2::pixels::PixelFormatEnum;
+
+
+#[test]
+fn pixel_format() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2VdiBuilder::new(&sdl, 64, 32, "blah")
+            .pixel_format(PixelFormatEnum::ABGR8888)
+            .build().unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0xFFFF; 16]);
+    vdi.commit().unwrap();
+    assert_eq!(vdi.get_point((31, 0)), 255);
+    assert_eq!(vdi.get_point((32, 0)), 0);
+}
+
+#[test]
+fn unsupported_pixel_format() {
+    let sdl = sdl2::init().unwrap();
+    let result = vdi::SDL2VdiBuilder::new(&sdl, 64, 32, "blah")
+        .pixel_format(PixelFormatEnum::RGB24)
+        .build();
+
+    match result {
+        Err(vdi::VdiError::FromSdl(_)) => (),
+        _ => panic!("Expected an error for a 24-bit format"),
+    }
+}
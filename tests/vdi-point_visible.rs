@@ -0,0 +1,26 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn point_visible() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    assert!(vdi.point_visible((0, 0)));
+    assert!(vdi.point_visible((31, 15)));
+    assert!(!vdi.point_visible((32, 15)));
+    assert!(!vdi.point_visible((31, 16)));
+
+    // The origin moves the visible region.
+    vdi.set_origin(-8, 4);
+    assert!(!vdi.point_visible((7, 0)));
+    assert!(vdi.point_visible((8, 0)));
+    assert!(vdi.point_visible((39, 11)));
+    assert!(!vdi.point_visible((40, 11)));
+    assert!(!vdi.point_visible((39, 12)));
+}
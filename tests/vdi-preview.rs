@@ -0,0 +1,40 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+fn presented(vdi: &mut vdi::SDL2Vdi, at: (usize, usize)) -> u8 {
+    vdi.commit().unwrap();
+    vdi.with_pixels(|bits, span| bits[at.1 * span + at.0 * 4]).unwrap()
+}
+
+#[test]
+fn preview() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 32), &[0; 16]);
+    vdi.frame((2, 2), (30, 30), 0xFFFF);
+    let base = vdi.snapshot();
+
+    vdi.begin_preview();
+    vdi.rect((8, 8), (16, 16), &[0xFFFF; 16]);
+    vdi.invert_line((0, 2), 32);
+
+    assert_eq!(presented(&mut vdi, (10, 10)), 255);
+    assert_eq!(presented(&mut vdi, (5, 2)), 0);
+    assert_eq!(presented(&mut vdi, (20, 20)), 0);
+    assert_eq!(vdi.get_point((10, 10)), 255);
+
+    vdi.end_preview();
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &base, 32);
+    assert_eq!(presented(&mut vdi, (10, 10)), 0);
+    assert_eq!(presented(&mut vdi, (5, 2)), 255);
+
+    // Without an open preview, ending one changes nothing.
+    vdi.end_preview();
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &base, 32);
+}
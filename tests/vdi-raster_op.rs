@@ -0,0 +1,49 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::RasterOp;
+
+
+#[test]
+fn raster_op_bits() {
+    assert_eq!(vdi::raster_op_bits(RasterOp::Copy),     0xAA);
+    assert_eq!(vdi::raster_op_bits(RasterOp::NotSrc),   0x55);
+    assert_eq!(vdi::raster_op_bits(RasterOp::Or),       0xEE);
+    assert_eq!(vdi::raster_op_bits(RasterOp::And),      0x88);
+    assert_eq!(vdi::raster_op_bits(RasterOp::Xor),      0x66);
+    assert_eq!(vdi::raster_op_bits(RasterOp::Erase),    0x44);
+    assert_eq!(vdi::raster_op_bits(RasterOp::NotDst),   0x33);
+    assert_eq!(vdi::raster_op_bits(RasterOp::Nop),      0xCC);
+    assert_eq!(vdi::raster_op_bits(RasterOp::ClearDst), 0x00);
+    assert_eq!(vdi::raster_op_bits(RasterOp::SetDst),   0xFF);
+}
+
+#[test]
+fn or_xor() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 2, "blah").unwrap();
+
+    // Each row's destination is white on the left half and black on the right;
+    // the source alternates between set and clear bits every four pixels.
+    let source : [u16; 1] = [0x0F0F];
+
+    vdi.hline((0, 0), 8, 0xFFFF);
+    vdi.hline((8, 0), 16, 0x0000);
+    vdi.copy_line((0, 0), 16, &source, (0, 0), 16, vdi::raster_op_bits(RasterOp::Or));
+
+    vdi.hline((0, 1), 8, 0xFFFF);
+    vdi.hline((8, 1), 16, 0x0000);
+    vdi.copy_line((0, 0), 16, &source, (0, 1), 16, vdi::raster_op_bits(RasterOp::Xor));
+
+    for x in 0..16 {
+        let d = x < 8;
+        let s = ((source[0] >> x) & 1) != 0;
+        let expect = |b: bool| if b { 255 } else { 0 };
+
+        assert_eq!(vdi.get_point((x, 0)), expect(d | s), "OR at {}", x);
+        assert_eq!(vdi.get_point((x, 1)), expect(d ^ s), "XOR at {}", x);
+    }
+}
@@ -0,0 +1,47 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+// Every row differs from every other, so a vertical phase shift is visible.
+static STAIRS : [u16; 16] = [
+    0x0001, 0x0003, 0x0007, 0x000F,
+    0x001F, 0x003F, 0x007F, 0x00FF,
+    0x01FF, 0x03FF, 0x07FF, 0x0FFF,
+    0x1FFF, 0x3FFF, 0x7FFF, 0xFFFF,
+];
+
+
+#[test]
+fn rect_offset() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 128, 64, "blah").unwrap();
+
+    vdi.rect((0, 0), (32, 32), &STAIRS);
+    vdi.rect_offset((64, 0), (96, 32), &STAIRS, (0, 3));
+
+    // Row y of the offset fill uses the pattern row which row y+3 of the plain fill does.
+    for y in 0..29 {
+        for x in 0..32 {
+            assert_eq!(
+                vdi.get_point((64 + x, y)), vdi.get_point((x, y + 3)),
+                "Point ({}, {})", x, y
+            );
+        }
+    }
+
+    vdi.rect_offset((64, 32), (96, 64), &STAIRS, (5, 0));
+
+    // Likewise, column x of this fill matches column x+5 of the plain fill.
+    for y in 0..32 {
+        for x in 0..27 {
+            assert_eq!(
+                vdi.get_point((64 + x, 32 + y)), vdi.get_point((x + 5, y)),
+                "Point ({}, {})", x, y
+            );
+        }
+    }
+}
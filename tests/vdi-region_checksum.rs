@@ -0,0 +1,44 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn region_checksum() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.rect((0, 0), (64, 64), &vdi::GRAY50);
+    let before = vdi.region_checksum((16, 16), (16, 16));
+    assert_eq!(vdi.region_checksum((16, 16), (16, 16)), before);
+
+    // Drawing around the region leaves its checksum alone...
+    vdi.frame((15, 15), (33, 33), 0xFFFF);
+    vdi.rect((40, 40), (64, 64), &[0; 16]);
+    assert_eq!(vdi.region_checksum((16, 16), (16, 16)), before);
+
+    // ...but changing a single pixel within it doesn't.
+    vdi.invert_line((31, 31), 32);
+    assert!(vdi.region_checksum((16, 16), (16, 16)) != before);
+}
+
+#[test]
+fn region_checksum_clipped() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.rect((0, 0), (64, 64), &vdi::GRAY50);
+
+    // The parts of a region hanging off the surface don't count.
+    let visible = vdi.region_checksum((48, 56), (16, 8));
+    assert_eq!(vdi.region_checksum((48, 56), (100, 100)), visible);
+    assert_eq!(vdi.region_checksum((65535, 65535), (10, 10)), vdi.region_checksum((0, 0), (0, 0)));
+
+    // The origin applies, as it does to get_point.
+    vdi.set_origin(-40, -50);
+    assert_eq!(vdi.region_checksum((88, 106), (16, 8)), visible);
+}
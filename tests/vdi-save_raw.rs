@@ -0,0 +1,73 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+use std::io;
+
+
+#[test]
+fn save_raw() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 40, 24, "blah").unwrap();
+
+    vdi.rect((0, 0), (40, 24), &vdi::GRAY50);
+    vdi.frame((3, 2), (37, 20), 0xFFFF);
+    let drawn = vdi.snapshot();
+
+    let mut saved = Vec::new();
+    vdi.save_raw(&mut saved).unwrap();
+    assert_eq!(&saved[0..4], &[40, 0, 24, 0]);
+    assert_eq!(saved.len(), 4 + 40 * 24);
+
+    vdi.rect((0, 0), (40, 24), &[0; 16]);
+    vdi.load_raw(&mut io::Cursor::new(saved)).unwrap();
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &drawn, 40);
+}
+
+#[test]
+fn load_raw_mismatched() {
+    let sdl = sdl2::init().unwrap();
+    let mut small = vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+    let mut large = vdi::SDL2Vdi::new(&sdl, 32, 16, "blah").unwrap();
+
+    small.rect((0, 0), (16, 16), &vdi::GRAY50);
+    let mut saved = Vec::new();
+    small.save_raw(&mut saved).unwrap();
+
+    let before = large.snapshot();
+    let err = large.load_raw(&mut io::Cursor::new(saved)).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    vdi::assert_snapshots_eq_with_width(&large.snapshot(), &before, 32);
+
+    // A truncated file fails too.
+    let err = large.load_raw(&mut io::Cursor::new(vec![32, 0, 16])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn load_raw_truncated_pixels() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 4, 2, "blah").unwrap();
+
+    vdi.rect((0, 0), (4, 2), &vdi::GRAY50);
+    let before = vdi.snapshot();
+
+    // A file ending part way through its pixels changes nothing.
+    let err = vdi.load_raw(&mut io::Cursor::new(vec![4, 0, 2, 0, 255, 255, 255])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &before, 4);
+}
+
+#[test]
+fn load_raw_normalizes() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 4, 2, "blah").unwrap();
+
+    // Stray values load as black or white, split at 128.
+    let saved = vec![4, 0, 2, 0, 0, 1, 127, 128, 129, 200, 254, 255];
+    vdi.load_raw(&mut io::Cursor::new(saved)).unwrap();
+    assert_eq!(vdi.snapshot(), vec![0, 0, 0, 255, 255, 255, 255, 255]);
+}
@@ -0,0 +1,29 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn scale() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2VdiBuilder::new(&sdl, 320, 240, "blah")
+            .scale(2)
+            .build().unwrap();
+
+    assert_eq!(vdi.dimensions(), (320, 240));
+
+    // The far corner of the logical surface is drawable...
+    vdi.draw_point((319, 239), 255);
+    assert_eq!(vdi.get_point((319, 239)), 255);
+
+    // ...but anything beyond it is off-surface, even though the window is larger.
+    vdi.draw_point((320, 240), 255);
+    assert_eq!(vdi.get_point((320, 240)), 0);
+    vdi.draw_point((639, 479), 255);
+    assert_eq!(vdi.get_point((639, 479)), 0);
+
+    vdi.commit().unwrap();
+}
@@ -0,0 +1,58 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+// A 5x3 tile, so that no row or column repeats.
+static TILE : [u16; 3] = [0b00011, 0b00100, 0b11000];
+
+
+fn tile_bit(x: u16, y: u16) -> bool {
+    (TILE[y as usize] >> x) & 1 != 0
+}
+
+fn check(vdi: &vdi::VDI, offset: (u16, u16)) {
+    for y in 0..32 {
+        for x in 0..32 {
+            let inside = (x >= 3) && (x < 23) && (y >= 2) && (y < 19);
+            let expected = inside && tile_bit((x - 3 + offset.0) % 5, (y - 2 + offset.1) % 3);
+            assert_eq!(vdi.get_point((x, y)), if expected { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn scroll_tiled() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    // Scrolling by two pixels shifts every row of the tiling two pixels left,
+    // with the first two columns of each tile wrapping around to the right.
+    vdi.scroll_tiled((3, 2), (23, 19), &TILE, (5, 3), (2, 0));
+    check(vdi, (2, 0));
+    assert_eq!(vdi.get_point((3, 2)), 0);
+    assert_eq!(vdi.get_point((6, 2)), 255);
+    assert_eq!(vdi.get_point((7, 2)), 255);
+
+    // Offsets wrap modulo the tile size, in both directions.
+    vdi.scroll_tiled((3, 2), (23, 19), &TILE, (5, 3), (13, 7));
+    check(vdi, (3, 1));
+}
+
+#[test]
+fn scroll_tiled_unscrolled() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.tile_rect((3, 2), (23, 19), &TILE, (5, 3));
+    let tiled = vdi.snapshot();
+
+    vdi.rect((0, 0), (32, 32), &[0; 16]);
+    vdi.scroll_tiled((3, 2), (23, 19), &TILE, (5, 3), (0, 0));
+    vdi::assert_snapshots_eq_with_width(&vdi.snapshot(), &tiled, 32);
+    check(vdi, (0, 0));
+}
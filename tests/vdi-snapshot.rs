@@ -0,0 +1,69 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+static DESKTOP : [u16; 16] = [
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+    0xAAAA, 0x5555, 0xAAAA, 0x5555,
+];
+
+
+fn draw_window(vdi: &mut vdi::VDI) {
+    vdi.rect((0, 0), (64, 64), &DESKTOP);
+    vdi.rect((10, 10), (40, 30), &[0xFFFF; 16]);
+    vdi.frame((10, 10), (40, 30), 0x0000);
+}
+
+#[test]
+fn snapshot() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    draw_window(vdi);
+    let first = vdi.snapshot();
+    assert_eq!(first.len(), 64 * 64);
+    assert_eq!(first[0], vdi.get_point((0, 0)));
+    assert_eq!(first[64 * 20 + 20], vdi.get_point((20, 20)));
+
+    vdi.rect((0, 0), (64, 64), &[0; 16]);
+    draw_window(vdi);
+    let second = vdi.snapshot();
+
+    vdi::assert_snapshots_eq(&first, &second);
+}
+
+#[test]
+#[should_panic(expected = "Snapshots differ at offset 980")]
+fn snapshot_differs() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    draw_window(vdi);
+    let first = vdi.snapshot();
+    vdi.draw_point((20, 15), 0);
+    let second = vdi.snapshot();
+
+    vdi::assert_snapshots_eq(&first, &second);
+}
+
+#[test]
+#[should_panic(expected = "Snapshots differ at (20, 15)")]
+fn snapshot_differs_with_width() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    draw_window(vdi);
+    let first = vdi.snapshot();
+    vdi.draw_point((20, 15), 0);
+    let second = vdi.snapshot();
+
+    vdi::assert_snapshots_eq_with_width(&first, &second, 64);
+}
@@ -0,0 +1,52 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn styled_polyline_continues_across_joins() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 8, "blah").unwrap();
+
+    // Two collinear segments, joined at x=10, which falls mid-dash.
+    vdi.styled_polyline(&[(0, 2), (10, 2), (40, 2)], 4, 3, 255);
+    vdi.styled_polyline(&[(0, 4), (40, 4)], 4, 3, 255);
+
+    for x in 0..64 {
+        let expected = if (x <= 40) && (x % 7 < 4) { 255 } else { 0 };
+        assert_eq!(vdi.get_point((x, 2)), expected, "Joined, column {}", x);
+        assert_eq!(vdi.get_point((x, 4)), expected, "Single, column {}", x);
+    }
+}
+
+#[test]
+fn styled_polyline_closed() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    // A closed polyline traces the same perimeter as a frame from (4, 4) to (20, 20).
+    vdi.styled_polyline(&[(4, 4), (19, 4), (19, 19), (4, 19), (4, 4)], 3, 2, 255);
+    let polyline = vdi.snapshot();
+
+    vdi.rect((0, 0), (32, 32), &[0; 16]);
+    vdi.dotted_frame((4, 4), (20, 20), 3, 2, 255);
+
+    vdi::assert_snapshots_eq_with_width(&polyline, &vdi.snapshot(), 32);
+}
+
+#[test]
+fn styled_polyline_diagonal() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    vdi.styled_polyline(&[(10, 10), (0, 0)], 1, 1, 255);
+    for i in 0..11 {
+        let expected = if (10 - i) % 2 == 0 { 255 } else { 0 };
+        assert_eq!(vdi.get_point((i, i)), expected, "Point ({}, {})", i, i);
+    }
+}
@@ -34,17 +34,13 @@ fn text() {
     let sdl = sdl2::init().unwrap();
     let vdi : &mut vdi::VDI =
         &mut vdi::SDL2Vdi::new(&sdl, 640, 480, "blah").unwrap();
-    let mut t = font::TextContext{
-        vdi: vdi,
-        font: font::borrow_system_font(),
-        left: 0,
-        baseline: 0,
-        strike_fn: 0b0101,
-        left_margin: 8,
-        right_margin: 128,
-        top_margin: 8,
-        bottom_margin: 24,
-    };
+    let mut t = font::TextContext::new(vdi, font::borrow_system_font());
+    t.baseline = 0;
+    t.strike_fn = 0b0101;
+    t.left_margin = 8;
+    t.right_margin = 128;
+    t.top_margin = 8;
+    t.bottom_margin = 24;
 
     t.vdi.rect((0, 0), (640, 480), &DESKTOP);
     put_string(&mut t, 0);
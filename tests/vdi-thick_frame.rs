@@ -0,0 +1,56 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+#[test]
+fn thick_frame() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    vdi.thick_frame((10, 12), (40, 30), 3, 0xFFFF);
+
+    for y in 0..64 {
+        for x in 0..64 {
+            let outer = (x >= 10) && (x < 40) && (y >= 12) && (y < 30);
+            let inner = (x >= 13) && (x < 37) && (y >= 15) && (y < 27);
+            let expected = if outer && !inner { 255 } else { 0 };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn thick_frame_pattern_continuous() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+
+    // The band carries the pattern exactly as a solid rectangle would.
+    vdi.thick_frame((10, 12), (40, 30), 4, 0x3C3C);
+    for y in 12..30 {
+        for x in 10..40 {
+            let in_band = (x < 14) || (x >= 36) || (y < 16) || (y >= 26);
+            let expected = if in_band && ((0x3C3C >> (x & 15)) & 1 != 0) { 255 } else { 0 };
+            assert_eq!(vdi.get_point((x, y)), expected, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn thick_frame_fills_when_too_thick() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 32, 32, "blah").unwrap();
+
+    vdi.thick_frame((4, 4), (14, 24), 6, 0xFFFF);
+    for y in 0..32 {
+        for x in 0..32 {
+            let inside = (x >= 4) && (x < 14) && (y >= 4) && (y < 24);
+            assert_eq!(vdi.get_point((x, y)), if inside { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn threshold() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+
+    // By default, pens split in half.
+    vdi.draw_point((0, 0), 150);
+    assert_eq!(vdi.get_point((0, 0)), 255);
+
+    vdi.set_threshold(200);
+    vdi.draw_point((0, 0), 150);
+    assert_eq!(vdi.get_point((0, 0)), 0);
+    vdi.draw_point((1, 0), 199);
+    assert_eq!(vdi.get_point((1, 0)), 0);
+    vdi.draw_point((2, 0), 200);
+    assert_eq!(vdi.get_point((2, 0)), 255);
+
+    // Batches and stippling follow suit.
+    vdi.begin_batch().draw_point((3, 0), 150);
+    assert_eq!(vdi.get_point((3, 0)), 0);
+
+    vdi.rect((0, 2), (16, 4), &[0xFFFF; 16]);
+    vdi.copy_rect_stippled((0, 0), 16, &[0xFFFF, 0xFFFF], (0, 2), (16, 2), 150);
+    assert_eq!(vdi.get_point((0, 2)), 0);
+    assert_eq!(vdi.get_point((1, 2)), 255);
+}
+
+#[test]
+fn threshold_error_diffused() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 16, 16, "blah").unwrap();
+    let gray = [150u8; 4];
+
+    // A lone gray pixel rounds as any pen would, with nothing left to diffuse its error into.
+    vdi.copy_rect_error_diffused((0, 0), 1, &gray, (0, 0), (1, 1));
+    assert_eq!(vdi.get_point((0, 0)), 255);
+
+    vdi.set_threshold(200);
+    assert_eq!(vdi.threshold(), 200);
+    vdi.copy_rect_error_diffused((0, 0), 1, &gray, (0, 0), (1, 1));
+    assert_eq!(vdi.get_point((0, 0)), 0);
+
+    // Viewports round as their surface does.
+    let mut view = vdi::Viewport::new(&mut vdi, (4, 4), (4, 4));
+    view.copy_rect_error_diffused((0, 0), 1, &gray, (0, 0), (1, 1));
+    assert_eq!(view.get_point((0, 0)), 0);
+}
@@ -0,0 +1,50 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+
+
+// A 4x4 tile: a diagonal with one extra pixel, so that no row or column repeats.
+static TILE : [u16; 4] = [0b0011, 0b0010, 0b0100, 0b1000];
+
+
+fn tile_bit(x: u16, y: u16) -> bool {
+    (TILE[y as usize] >> x) & 1 != 0
+}
+
+#[test]
+fn tile_rect() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 40, 40, "blah").unwrap();
+
+    vdi.tile_rect((5, 7), (25, 27), &TILE, (4, 4));
+
+    for y in 0..40 {
+        for x in 0..40 {
+            let inside = (x >= 5) && (x < 25) && (y >= 7) && (y < 27);
+            let expected = inside && tile_bit((x - 5) % 4, (y - 7) % 4);
+            assert_eq!(vdi.get_point((x, y)), if expected { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn tile_rect_partial_tiles() {
+    let sdl = sdl2::init().unwrap();
+    let vdi : &mut vdi::VDI =
+        &mut vdi::SDL2Vdi::new(&sdl, 40, 40, "blah").unwrap();
+
+    // The right and bottom edges cut the last tiles short, overwriting what's beneath.
+    vdi.rect((0, 0), (40, 40), &[0xFFFF; 16]);
+    vdi.tile_rect((2, 2), (12, 9), &TILE, (4, 4));
+
+    for y in 0..40 {
+        for x in 0..40 {
+            let inside = (x >= 2) && (x < 12) && (y >= 2) && (y < 9);
+            let expected = !inside || tile_bit((x - 2) % 4, (y - 2) % 4);
+            assert_eq!(vdi.get_point((x, y)), if expected { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
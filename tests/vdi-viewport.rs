@@ -0,0 +1,115 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+static WHITE : [u16; 16] = [0xFFFF; 16];
+
+
+fn count_set(vdi: &vdi::VDI) -> usize {
+    vdi.snapshot().iter().filter(|&&p| p != 0).count()
+}
+
+#[test]
+fn viewport() {
+    let sdl = sdl2::init().unwrap();
+    let mut screen = vdi::SDL2Vdi::new(&sdl, 320, 240, "blah").unwrap();
+
+    {
+        let mut v = vdi::Viewport::new(&mut screen, (100, 100), (50, 40));
+        assert_eq!(v.dimensions(), (50, 40));
+
+        v.draw_point((0, 0), 255);
+        assert_eq!(v.get_point((0, 0)), 255);
+
+        // Only the part of the rectangle within the viewport is drawn.
+        v.rect((40, 30), (80, 60), &WHITE);
+
+        // Entirely outside; nothing is drawn.
+        v.draw_point((50, 0), 255);
+        v.hline((0, 40), 50, 0xFFFF);
+        v.vline((60, 0), 40, 0xFFFF);
+        assert!(!v.point_visible((50, 0)));
+        assert!(v.point_visible((49, 39)));
+    }
+
+    assert_eq!(screen.get_point((100, 100)), 255);
+    for y in 0..240 {
+        for x in 0..320 {
+            let inside = (x >= 140) && (x < 150) && (y >= 130) && (y < 140);
+            if !inside && ((x, y) != (100, 100)) {
+                assert_eq!(screen.get_point((x, y)), 0, "Point ({}, {})", x, y);
+            }
+            else {
+                assert_eq!(screen.get_point((x, y)), 255, "Point ({}, {})", x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn viewport_origin() {
+    let sdl = sdl2::init().unwrap();
+    let mut screen = vdi::SDL2Vdi::new(&sdl, 320, 240, "blah").unwrap();
+
+    {
+        let mut v = vdi::Viewport::new(&mut screen, (100, 100), (50, 40));
+        v.set_origin(-10, 5);
+        v.draw_point((12, 0), 255);
+
+        // Scrolled off the left edge of the viewport, though still on the screen.
+        v.draw_point((5, 0), 255);
+    }
+
+    assert_eq!(screen.get_point((102, 105)), 255);
+    assert_eq!(count_set(&screen), 1);
+}
+
+#[test]
+fn nested_viewports() {
+    let sdl = sdl2::init().unwrap();
+    let mut screen = vdi::SDL2Vdi::new(&sdl, 320, 240, "blah").unwrap();
+
+    {
+        let mut outer = vdi::Viewport::new(&mut screen, (100, 100), (50, 40));
+        let mut inner = vdi::Viewport::new(&mut outer, (10, 20), (100, 100));
+
+        // The inner viewport's position composes with the outer's,
+        // and its drawing is clipped to both.
+        inner.rect((0, 0), (100, 100), &WHITE);
+        inner.draw_point((45, 0), 255);
+    }
+
+    for y in 0..240 {
+        for x in 0..320 {
+            let inside = (x >= 110) && (x < 150) && (y >= 120) && (y < 140);
+            assert_eq!(screen.get_point((x, y)), if inside { 255 } else { 0 }, "Point ({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn viewport_copy_rect() {
+    let sdl = sdl2::init().unwrap();
+    let mut screen = vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+    let mut expected = vdi::SDL2Vdi::new(&sdl, 64, 64, "blah").unwrap();
+    let bits : Vec<u16> = (0..16).map(|y| 0x1234u16.rotate_left(y)).collect();
+
+    {
+        let mut v = vdi::Viewport::new(&mut screen, (20, 20), (10, 10));
+        v.set_origin(-4, 3);
+        v.copy_rect((0, 0), 16, &bits, (0, 0), (16, 16), 0b1010);
+    }
+
+    // Draw the whole bitmap, then erase everything outside the viewport.
+    expected.copy_rect((0, 0), 16, &bits, (16, 23), (16, 16), 0b1010);
+    expected.rect((0, 0), (64, 20), &[0; 16]);
+    expected.rect((0, 30), (64, 64), &[0; 16]);
+    expected.rect((0, 0), (20, 64), &[0; 16]);
+    expected.rect((30, 0), (64, 64), &[0; 16]);
+
+    vdi::assert_snapshots_eq_with_width(&expected.snapshot(), &screen.snapshot(), 64);
+}
@@ -0,0 +1,29 @@
+extern crate sdl2;
+extern crate gemini;
+
+
+use gemini::vdi;
+use gemini::vdi::VDI;
+
+
+#[test]
+fn with_pixels() {
+    let sdl = sdl2::init().unwrap();
+    let mut vdi = vdi::SDL2Vdi::new(&sdl, 64, 32, "blah").unwrap();
+
+    vdi.rect((0, 0), (64, 32), &vdi::GRAY50);
+    vdi.commit().unwrap();
+
+    let samples = vdi.with_pixels(|bits, span| {
+        let quad = |x: usize, y: usize| {
+            let at = y * span + x * 4;
+            (bits[at], bits[at + 1], bits[at + 2], bits[at + 3])
+        };
+        (quad(0, 0), quad(1, 0), quad(0, 1), quad(63, 31))
+    }).unwrap();
+
+    let white = (255, 255, 255, 255);
+    let black = (0, 0, 0, 0);
+    assert_eq!(samples, (black, white, white, black));
+    assert_eq!(vdi.get_point((1, 0)), 255);
+}